@@ -0,0 +1,179 @@
+use std::collections::{BTreeMap, VecDeque};
+
+fn main() {
+    let patterns = [&b"he"[..], b"she", b"his", b"hers", b"e"];
+    let ac = AhoCorasick::new(&patterns);
+    dbg!(ac.find_all(b"ushers"));
+
+    // "ab", "bc" を含まない，文字 a, b, c からなる長さ 10 の文字列の個数
+    let ac = AhoCorasick::new(&[&b"ab"[..], b"bc"]);
+    let table = ac.transition_table(b"abc");
+    let mut dp = vec![0u64; ac.len()];
+    dp[0] = 1;
+    for _ in 0..10 {
+        let mut next = vec![0; ac.len()];
+        for v in 0..ac.len() {
+            for &to in &table[v] {
+                if ac.count(to) == 0 {
+                    next[to] += dp[v];
+                }
+            }
+        }
+        dp = next;
+    }
+    dbg!(dp.iter().sum::<u64>());
+}
+
+#[derive(Clone, Debug)]
+pub struct Node<T> {
+    pub next: BTreeMap<T, usize>,
+    pub fail: usize,
+    /// fail をたどって最初に見つかる，パターンの終端であるノード
+    pub output: Option<usize>,
+    /// このノードで終わるパターンの番号
+    pub ids: Vec<usize>,
+    pub depth: usize,
+}
+
+pub struct AhoCorasick<T> {
+    nodes: Vec<Node<T>>,
+    lens: Vec<usize>,
+    count: Vec<usize>,
+}
+
+impl<T: Ord + Clone> AhoCorasick<T> {
+    pub fn new<P: AsRef<[T]>>(patterns: &[P]) -> Self {
+        let new_node = |depth| Node {
+            next: BTreeMap::new(),
+            fail: 0,
+            output: None,
+            ids: vec![],
+            depth,
+        };
+        let mut nodes = vec![new_node(0)];
+        let mut lens = vec![];
+        for (id, p) in patterns.iter().enumerate() {
+            let p = p.as_ref();
+            let mut v = 0;
+            for c in p {
+                v = match nodes[v].next.get(c) {
+                    Some(&to) => to,
+                    None => {
+                        let to = nodes.len();
+                        nodes.push(new_node(nodes[v].depth + 1));
+                        nodes[v].next.insert(c.clone(), to);
+                        to
+                    }
+                };
+            }
+            nodes[v].ids.push(id);
+            lens.push(p.len());
+        }
+        let mut ac = Self {
+            nodes,
+            lens,
+            count: vec![],
+        };
+        let mut order = vec![];
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            let children: Vec<(T, usize)> = ac.nodes[v]
+                .next
+                .iter()
+                .map(|(c, &to)| (c.clone(), to))
+                .collect();
+            for (c, to) in children {
+                let fail = if v == 0 {
+                    0
+                } else {
+                    ac.next_state(ac.nodes[v].fail, &c)
+                };
+                ac.nodes[to].fail = fail;
+                ac.nodes[to].output = if ac.nodes[fail].ids.is_empty() {
+                    ac.nodes[fail].output
+                } else {
+                    Some(fail)
+                };
+                queue.push_back(to);
+            }
+        }
+        let mut count = vec![0; ac.nodes.len()];
+        for v in order {
+            count[v] = ac.nodes[v].ids.len() + if v == 0 { 0 } else { count[ac.nodes[v].fail] };
+        }
+        ac.count = count;
+        ac
+    }
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    pub fn nodes(&self) -> &[Node<T>] {
+        &self.nodes
+    }
+    /// 状態 `v` で `c` を読んだ後の状態
+    pub fn next_state(&self, mut v: usize, c: &T) -> usize {
+        loop {
+            if let Some(&to) = self.nodes[v].next.get(c) {
+                return to;
+            }
+            if v == 0 {
+                return 0;
+            }
+            v = self.nodes[v].fail;
+        }
+    }
+    /// `table[v][i]`: 状態 `v` で `alphabet[i]` を読んだ後の状態
+    pub fn transition_table(&self, alphabet: &[T]) -> Vec<Vec<usize>> {
+        let mut table = vec![vec![0; alphabet.len()]; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(v) = queue.pop_front() {
+            for (i, c) in alphabet.iter().enumerate() {
+                table[v][i] = match self.nodes[v].next.get(c) {
+                    Some(&to) => {
+                        queue.push_back(to);
+                        to
+                    }
+                    None if v == 0 => 0,
+                    None => table[self.nodes[v].fail][i],
+                };
+            }
+        }
+        table
+    }
+    /// 状態 `v` に対応する文字列の接尾辞になっているパターンの個数
+    pub fn count(&self, v: usize) -> usize {
+        self.count[v]
+    }
+    /// 状態 `v` に対応する文字列の接尾辞になっているパターンの番号
+    pub fn matches(&self, v: usize) -> Vec<usize> {
+        let mut res = vec![];
+        let mut u = if self.nodes[v].ids.is_empty() {
+            self.nodes[v].output
+        } else {
+            Some(v)
+        };
+        while let Some(w) = u {
+            res.extend_from_slice(&self.nodes[w].ids);
+            u = self.nodes[w].output;
+        }
+        res
+    }
+    /// (出現位置の始点, パターンの番号) をすべて
+    pub fn find_all(&self, text: &[T]) -> Vec<(usize, usize)> {
+        let mut res = vec![];
+        let mut v = 0;
+        for (i, c) in text.iter().enumerate() {
+            v = self.next_state(v, c);
+            for id in self.matches(v) {
+                res.push((i + 1 - self.lens[id], id));
+            }
+        }
+        res
+    }
+}
@@ -0,0 +1,73 @@
+fn main() {
+    // 隣り合わない k 個を選んだときの和の最大値
+    let a = [5i64, 1, 4, 9, 2, 6, 8, 3];
+    let max_sum = |k: usize| {
+        -aliens_trick(k, |lambda| {
+            // (選んだ個数で penalty をつけた和の最小値, 個数) を，直前を選んだかどうかで
+            let (mut skip, mut take) = ((0, 0), (i64::MAX, 0));
+            for &x in &a {
+                let next_take = (skip.0 - x + lambda, skip.1 + 1);
+                skip = skip.min(take);
+                take = next_take;
+            }
+            skip.min(take)
+        })
+    };
+    dbg!((0..=4).map(max_sum).collect::<Vec<_>>());
+}
+
+/// 個数 `j` のときの最小値 `g(j)` が `j` について凸なとき，`g(k)` を返す
+///
+/// `eval(λ)` は `min_j (g(j) + λj)` と，最小値をとる `j` の1つを返す．最小値をとる `j` が複数あるときはどれを返してもよい．
+/// `k` は実現可能であること．`λ` は整数で，`|λ|` は隣り合う `g` の差の最大値の2倍程度まで動く．
+pub fn aliens_trick(k: usize, mut eval: impl FnMut(i64) -> (i64, usize)) -> i64 {
+    let k_i64 = k as i64;
+    // c(λ) は λ について単調非増加．c(lo) > k > c(hi) となる区間を探す
+    let (mut lo, mut hi) = (0, 0);
+    let (v, c) = eval(0);
+    let (mut v_lo, mut v_hi) = (v, v);
+    if c == k {
+        return v;
+    } else if c > k {
+        let mut d = 1;
+        loop {
+            hi = lo + d;
+            let (v, c) = eval(hi);
+            if c == k {
+                return v - hi * k_i64;
+            } else if c < k {
+                v_hi = v;
+                break;
+            }
+            (lo, v_lo) = (hi, v);
+            d *= 2;
+        }
+    } else {
+        let mut d = 1;
+        loop {
+            lo = hi - d;
+            let (v, c) = eval(lo);
+            if c == k {
+                return v - lo * k_i64;
+            } else if c > k {
+                v_lo = v;
+                break;
+            }
+            (hi, v_hi) = (lo, v);
+            d *= 2;
+        }
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        let (v, c) = eval(mid);
+        if c == k {
+            return v - mid * k_i64;
+        } else if c > k {
+            (lo, v_lo) = (mid, v);
+        } else {
+            (hi, v_hi) = (mid, v);
+        }
+    }
+    // k は λ = lo か λ = hi の最小値をとる j に含まれる．どの λ でも v - λk <= g(k)
+    (v_lo - lo * k_i64).max(v_hi - hi * k_i64)
+}
@@ -0,0 +1,133 @@
+#[allow(dead_code)]
+#[path = "xorshift.rs"]
+pub mod xorshift;
+
+use std::time::Instant;
+use xorshift::XorShift64;
+
+fn main() {
+    // 巡回セールスマン問題．2-opt 近傍で経路長を最小化する
+    let mut rng = XorShift64::new(1);
+    let ps: Vec<(f64, f64)> = (0..30).map(|_| (rng.gen_f64(), rng.gen_f64())).collect();
+    let len = |order: &Vec<usize>| {
+        (0..order.len())
+            .map(|i| {
+                let (a, b) = (ps[order[i]], ps[order[(i + 1) % order.len()]]);
+                (a.0 - b.0).hypot(a.1 - b.1)
+            })
+            .sum::<f64>()
+    };
+    let init: Vec<usize> = (0..ps.len()).collect();
+    dbg!(len(&init));
+    let mut annealer = Annealer::new(0.2, 0.1, 0.001);
+    let (best, score) = annealer.run(
+        init,
+        |order, rng| {
+            let mut order = order.clone();
+            let i = rng.gen_range(0..order.len());
+            let j = rng.gen_range(0..order.len());
+            order[i.min(j)..=i.max(j)].reverse();
+            order
+        },
+        |order| -len(order),
+    );
+    dbg!(-score, len(&best), annealer.iterations);
+}
+
+/// 制限時間のうちどれだけ経ったか
+#[derive(Clone, Debug)]
+pub struct TimeKeeper {
+    start: Instant,
+    limit: f64,
+}
+
+impl TimeKeeper {
+    /// 制限時間 `limit` 秒
+    pub fn new(limit: f64) -> Self {
+        Self {
+            start: Instant::now(),
+            limit,
+        }
+    }
+    /// 経過時間 (秒)
+    pub fn elapsed(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+    /// 経過時間の割合．`1` を超えうる
+    pub fn progress(&self) -> f64 {
+        self.elapsed() / self.limit
+    }
+    pub fn is_over(&self) -> bool {
+        self.elapsed() >= self.limit
+    }
+}
+
+/// 焼きなまし法．スコアを最大化する
+#[derive(Clone, Debug)]
+pub struct Annealer {
+    /// 制限時間 (秒)
+    pub time_limit: f64,
+    /// 開始時の温度
+    pub t0: f64,
+    /// 終了時の温度
+    pub t1: f64,
+    pub rng: XorShift64,
+    /// 時刻を調べる間隔 (反復回数)
+    pub check_interval: usize,
+    /// 直前の `run` での反復回数
+    pub iterations: usize,
+}
+
+impl Annealer {
+    pub fn new(time_limit: f64, t0: f64, t1: f64) -> Self {
+        Self {
+            time_limit,
+            t0,
+            t1,
+            rng: XorShift64::new(0),
+            check_interval: 100,
+            iterations: 0,
+        }
+    }
+    /// 経過時間の割合が `progress` のときの温度．`t0` から `t1` まで指数的に下げる
+    pub fn temperature(&self, progress: f64) -> f64 {
+        self.t0 * (self.t1 / self.t0).powf(progress)
+    }
+    /// 改善なら必ず，悪化なら確率 `exp(diff / 温度)` で受理する
+    pub fn accept(&mut self, diff: f64, temperature: f64) -> bool {
+        diff >= 0.0 || self.rng.gen_f64() < (diff / temperature).exp()
+    }
+    /// `init` から始めて，`neighbor` で近傍の状態を作り，`score` で評価する．最良の状態とそのスコアを返す
+    pub fn run<S: Clone>(
+        &mut self,
+        init: S,
+        mut neighbor: impl FnMut(&S, &mut XorShift64) -> S,
+        mut score: impl FnMut(&S) -> f64,
+    ) -> (S, f64) {
+        let time = TimeKeeper::new(self.time_limit);
+        let mut cur_score = score(&init);
+        let (mut best, mut best_score) = (init.clone(), cur_score);
+        let mut cur = init;
+        let mut temperature = self.t0;
+        self.iterations = 0;
+        loop {
+            if self.iterations.is_multiple_of(self.check_interval) {
+                let progress = time.progress();
+                if progress >= 1.0 {
+                    break;
+                }
+                temperature = self.temperature(progress);
+            }
+            self.iterations += 1;
+            let next = neighbor(&cur, &mut self.rng);
+            let next_score = score(&next);
+            if self.accept(next_score - cur_score, temperature) {
+                (cur, cur_score) = (next, next_score);
+                if cur_score > best_score {
+                    (best, best_score) = (cur.clone(), cur_score);
+                }
+            }
+        }
+        (best, best_score)
+    }
+}
@@ -0,0 +1,85 @@
+fn main() {
+    let cost = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+    dbg!(assignment(&cost));
+    // 横長: すべての行が割り当てられる
+    let cost = vec![vec![7, 3, 9, 1], vec![2, 8, 1, 6]];
+    dbg!(assignment(&cost));
+    // 縦長: 割り当てられない行がある
+    let cost = vec![vec![5, 1], vec![1, 5], vec![0, 0]];
+    dbg!(assignment(&cost));
+}
+
+/// (最小コスト, 各行に割り当てられた列)．`min(行数, 列数)` 個の組を作る．
+pub fn assignment(cost: &[Vec<i64>]) -> (i64, Vec<Option<usize>>) {
+    let n = cost.len();
+    let m = cost.first().map_or(0, Vec::len);
+    assert!(cost.iter().all(|row| row.len() == m), "not a matrix");
+    if n > m {
+        let transposed: Vec<Vec<i64>> = (0..m)
+            .map(|j| (0..n).map(|i| cost[i][j]).collect())
+            .collect();
+        let (total, col) = assignment(&transposed);
+        let mut row = vec![None; n];
+        for (j, i) in col.into_iter().enumerate() {
+            row[i.unwrap()] = Some(j);
+        }
+        return (total, row);
+    }
+    // 1-indexed，p[j]: 列 j に割り当てられた行 (0 は未割り当て)
+    let mut u = vec![0; n + 1];
+    let mut v = vec![0; m + 1];
+    let mut p = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut minv = vec![i64::MAX; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = i64::MAX;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let cur = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                if cur < minv[j] {
+                    minv[j] = cur;
+                    way[j] = j0;
+                }
+                if minv[j] < delta {
+                    delta = minv[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    minv[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+    let mut row = vec![None; n];
+    let mut total = 0;
+    for j in 1..=m {
+        if p[j] != 0 {
+            row[p[j] - 1] = Some(j - 1);
+            total += cost[p[j] - 1][j - 1];
+        }
+    }
+    (total, row)
+}
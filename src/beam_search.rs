@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+fn main() {
+    // ナップサック問題．i 番目の品物まで決めた状態から，入れるか入れないかで遷移する
+    let items = [
+        (12, 4),
+        (2, 2),
+        (1, 1),
+        (4, 10),
+        (1, 2),
+        (3, 7),
+        (5, 8),
+        (7, 9),
+    ];
+    let init = Knapsack {
+        items: &items,
+        capacity: 20,
+        i: 0,
+        weight: 0,
+        value: 0,
+        taken: vec![],
+    };
+    let best = BeamSearch::new(4, items.len()).run(init.clone());
+    dbg!(best.value, best.weight, &best.taken);
+    let best = BeamSearch::new(100, items.len()).run(init);
+    dbg!(best.value, best.weight, &best.taken);
+}
+
+#[derive(Clone)]
+struct Knapsack<'a> {
+    /// (重さ, 価値)
+    items: &'a [(u32, u32)],
+    capacity: u32,
+    i: usize,
+    weight: u32,
+    value: u32,
+    taken: Vec<usize>,
+}
+
+impl BeamState for Knapsack<'_> {
+    type Score = u32;
+    fn expand(&self, out: &mut Vec<Self>) {
+        let mut next = self.clone();
+        next.i += 1;
+        out.push(next.clone());
+        let (w, v) = self.items[self.i];
+        if self.weight + w <= self.capacity {
+            next.weight += w;
+            next.value += v;
+            next.taken.push(self.i);
+            out.push(next);
+        }
+    }
+    fn score(&self) -> u32 {
+        self.value
+    }
+    /// 同じ重さなら価値が大きいほうだけ残せばよい
+    fn hash(&self) -> u64 {
+        (self.i as u64) << 32 | self.weight as u64
+    }
+}
+
+/// ビームサーチの状態
+pub trait BeamState: Clone {
+    /// 大きいほどよい
+    type Score: Ord;
+    /// 1 手進めた状態を `out` に加える
+    fn expand(&self, out: &mut Vec<Self>);
+    fn score(&self) -> Self::Score;
+    /// 同じ値の状態は同じとみなして，スコアが最もよいものだけ残す
+    fn hash(&self) -> u64;
+    /// スコアが等しいとき，大きいほうを優先する
+    fn tie_break(&self) -> u64 {
+        0
+    }
+}
+
+/// 各深さでスコアの上位 `width` 個だけを残して，`depth` 手まで進める
+#[derive(Clone, Debug)]
+pub struct BeamSearch {
+    pub width: usize,
+    pub depth: usize,
+}
+
+impl BeamSearch {
+    pub fn new(width: usize, depth: usize) -> Self {
+        assert!(width >= 1);
+        Self { width, depth }
+    }
+    /// `depth` 手進めた状態のうちスコアが最もよいもの．途中で進めなくなったら，その手前の深さで最もよいもの
+    pub fn run<S: BeamState>(&self, init: S) -> S {
+        let mut beam = vec![init];
+        // 深さごとに確保しなおさないように，使い回す
+        let mut candidates = vec![];
+        let mut keyed: Vec<((S::Score, u64), S)> = vec![];
+        let mut seen = HashSet::new();
+        for _ in 0..self.depth {
+            for s in &beam {
+                s.expand(&mut candidates);
+            }
+            if candidates.is_empty() {
+                break;
+            }
+            // 重複を除く前に並べておき，同じ hash ならよいほうを残す
+            keyed.extend(
+                candidates
+                    .drain(..)
+                    .map(|s| ((s.score(), s.tie_break()), s)),
+            );
+            keyed.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+            beam.clear();
+            seen.clear();
+            for (_, s) in keyed.drain(..) {
+                if beam.len() == self.width {
+                    break;
+                }
+                if seen.insert(s.hash()) {
+                    beam.push(s);
+                }
+            }
+        }
+        // candidates が空でなければ beam も空でないので，beam は常に空でない
+        beam.into_iter()
+            .max_by(|a, b| (a.score(), a.tie_break()).cmp(&(b.score(), b.tie_break())))
+            .unwrap()
+    }
+}
@@ -0,0 +1,478 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+fn main() {
+    let a: BigInt = "123456789012345678901234567890".parse().unwrap();
+    let b = BigInt::from(-987654321i64);
+    dbg!(&a + &b, &a - &b, &a * &b, &a / &b, &a % &b);
+    dbg!(a.cmp(&b), -&a, b.abs());
+    // 100! は 158 桁
+    let fact = (1..=100u64).fold(BigInt::from(1u64), |acc, i| acc * BigInt::from(i));
+    println!("{fact}");
+    dbg!(fact.to_string().len());
+    // 2^2000 を 3^1000 で割る
+    let (q, r) = BigInt::from(2u64)
+        .pow(2000)
+        .div_rem(&BigInt::from(3u64).pow(1000));
+    dbg!(q, r.to_i128());
+    dbg!(
+        "-0".parse::<BigInt>().unwrap(),
+        "12a".parse::<BigInt>().is_err()
+    );
+}
+
+/// 1 桁
+const BASE: u32 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+/// これより短ければ筆算で掛ける
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// 多倍長整数．絶対値を `10^9` 進法で持つ
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    neg: bool,
+    /// 下の桁から．上の桁に `0` はない．`0` は空
+    mag: Vec<u32>,
+}
+
+fn trim(a: &mut Vec<u32>) {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// `a += b * BASE^shift`
+fn add_mag_at(a: &mut Vec<u32>, b: &[u32], shift: usize) {
+    if a.len() < b.len() + shift {
+        a.resize(b.len() + shift, 0);
+    }
+    let mut carry = 0;
+    let mut i = shift;
+    for &x in b {
+        let s = a[i] + x + carry;
+        (a[i], carry) = if s >= BASE { (s - BASE, 1) } else { (s, 0) };
+        i += 1;
+    }
+    while carry != 0 {
+        if i == a.len() {
+            a.push(0);
+        }
+        let s = a[i] + carry;
+        (a[i], carry) = if s >= BASE { (s - BASE, 1) } else { (s, 0) };
+        i += 1;
+    }
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = a.to_vec();
+    add_mag_at(&mut res, b, 0);
+    trim(&mut res);
+    res
+}
+
+/// `a -= b`．`a >= b` でなければならない
+fn sub_mag_assign(a: &mut Vec<u32>, b: &[u32]) {
+    let mut borrow = 0;
+    for (i, x) in a.iter_mut().enumerate() {
+        if i >= b.len() && borrow == 0 {
+            break;
+        }
+        let y = b.get(i).copied().unwrap_or(0) + borrow;
+        (*x, borrow) = if *x >= y {
+            (*x - y, 0)
+        } else {
+            (*x + BASE - y, 1)
+        };
+    }
+    debug_assert_eq!(borrow, 0);
+    trim(a);
+}
+
+fn mul_naive(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let t = res[i + j] as u64 + x as u64 * y as u64 + carry;
+            res[i + j] = (t % BASE as u64) as u32;
+            carry = t / BASE as u64;
+        }
+        res[i + b.len()] = carry as u32;
+    }
+    trim(&mut res);
+    res
+}
+
+/// Karatsuba 法
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < KARATSUBA_THRESHOLD {
+        return mul_naive(a, b);
+    }
+    if 2 * b.len() <= a.len() {
+        // 長さが大きく違うときは，長いほうを短いほうの長さで区切る
+        let mut res = vec![];
+        for (k, chunk) in a.chunks(b.len()).enumerate() {
+            add_mag_at(&mut res, &mul_mag(chunk, b), k * b.len());
+        }
+        trim(&mut res);
+        return res;
+    }
+    // a = a0 + a1 BASE^m, b = b0 + b1 BASE^m
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = mul_mag(a0, b0);
+    let z2 = mul_mag(a1, b1);
+    let mut z1 = mul_mag(&add_mag(a0, a1), &add_mag(b0, b1));
+    sub_mag_assign(&mut z1, &z0);
+    sub_mag_assign(&mut z1, &z2);
+    let mut res = z0;
+    add_mag_at(&mut res, &z1, m);
+    add_mag_at(&mut res, &z2, 2 * m);
+    trim(&mut res);
+    res
+}
+
+/// `a` を `d < BASE` で割った商と余り
+fn div_rem_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut q = vec![0; a.len()];
+    let mut r = 0u64;
+    for i in (0..a.len()).rev() {
+        let t = r * BASE as u64 + a[i] as u64;
+        q[i] = (t / d as u64) as u32;
+        r = t % d as u64;
+    }
+    trim(&mut q);
+    (q, r as u32)
+}
+
+/// Knuth の Algorithm D．`b` は空でない
+fn div_rem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_mag(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = div_rem_small(a, b[0]);
+        return (q, if r == 0 { vec![] } else { vec![r] });
+    }
+    let base = BASE as u64;
+    // 割る数の最上位の桁が BASE / 2 以上になるように，両方に d を掛けておく
+    let d = BASE / (b[b.len() - 1] + 1);
+    let mut u = mul_naive(a, &[d]);
+    u.resize(a.len() + 1, 0);
+    let v = mul_naive(b, &[d]);
+    let (n, m) = (v.len(), a.len() - b.len());
+    let mut q = vec![0; m + 1];
+    for j in (0..=m).rev() {
+        // 上の 2 桁から商の 1 桁を見積もる．大きすぎても高々 2
+        let num = u[j + n] as u64 * base + u[j + n - 1] as u64;
+        let mut qhat = num / v[n - 1] as u64;
+        let mut rhat = num % v[n - 1] as u64;
+        while qhat >= base || qhat * v[n - 2] as u64 > rhat * base + u[j + n - 2] as u64 {
+            qhat -= 1;
+            rhat += v[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+        // u[j..=j + n] -= qhat * v
+        let (mut borrow, mut carry) = (0i64, 0u64);
+        for i in 0..=n {
+            let p = qhat * v.get(i).copied().unwrap_or(0) as u64 + carry;
+            carry = p / base;
+            let t = u[i + j] as i64 - (p % base) as i64 - borrow;
+            (u[i + j], borrow) = if t < 0 {
+                ((t + base as i64) as u32, 1)
+            } else {
+                (t as u32, 0)
+            };
+        }
+        if borrow != 0 {
+            // 引きすぎたので 1 回分足し戻す
+            qhat -= 1;
+            let mut carry = 0;
+            for i in 0..=n {
+                let s = u[i + j] + v.get(i).copied().unwrap_or(0) + carry;
+                (u[i + j], carry) = if s >= BASE { (s - BASE, 1) } else { (s, 0) };
+            }
+        }
+        q[j] = qhat as u32;
+    }
+    trim(&mut q);
+    u.truncate(n);
+    trim(&mut u);
+    (q, div_rem_small(&u, d).0)
+}
+
+impl BigInt {
+    fn from_parts(neg: bool, mag: Vec<u32>) -> Self {
+        let neg = neg && !mag.is_empty();
+        Self { neg, mag }
+    }
+    pub fn zero() -> Self {
+        Self::default()
+    }
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+    pub fn is_negative(&self) -> bool {
+        self.neg
+    }
+    /// `-1`, `0`, `1`
+    pub fn signum(&self) -> i32 {
+        if self.neg {
+            -1
+        } else if self.mag.is_empty() {
+            0
+        } else {
+            1
+        }
+    }
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.mag.clone())
+    }
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut res = Self::from(1u64);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = &res * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        res
+    }
+    /// 0 に向かって丸めた商と，`self` と同じ符号の余り (`i64` などと同じ)．`rhs` が `0` なら panic
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        let (q, r) = div_rem_mag(&self.mag, &rhs.mag);
+        (
+            Self::from_parts(self.neg != rhs.neg, q),
+            Self::from_parts(self.neg, r),
+        )
+    }
+    /// `i128` に収まらなければ `None`
+    pub fn to_i128(&self) -> Option<i128> {
+        let mut res: i128 = 0;
+        for &x in self.mag.iter().rev() {
+            res = res.checked_mul(BASE as i128)?;
+            res = if self.neg {
+                res.checked_sub(x as i128)?
+            } else {
+                res.checked_add(x as i128)?
+            };
+        }
+        Some(res)
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(mut x: u128) -> Self {
+        let mut mag = vec![];
+        while x > 0 {
+            mag.push((x % BASE as u128) as u32);
+            x /= BASE as u128;
+        }
+        Self::from_parts(false, mag)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(x: i128) -> Self {
+        let mut res = Self::from(x.unsigned_abs());
+        res.neg = x < 0;
+        res
+    }
+}
+
+macro_rules! impl_from {
+    ($($t:ty => $via:ty),*) => {$(
+        impl From<$t> for BigInt {
+            fn from(x: $t) -> Self {
+                Self::from(x as $via)
+            }
+        }
+    )*};
+}
+
+impl_from!(u32 => u128, u64 => u128, usize => u128, i32 => i128, i64 => i128, isize => i128);
+
+impl FromStr for BigInt {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            // 標準ライブラリと同じエラーを返す
+            return Err(s.parse::<u32>().unwrap_err());
+        }
+        // 下から 9 桁ずつ
+        let digits = digits.as_bytes();
+        let mut mag: Vec<u32> = digits
+            .rchunks(BASE_DIGITS)
+            .map(|chunk| chunk.iter().fold(0, |acc, c| acc * 10 + (c - b'0') as u32))
+            .collect();
+        trim(&mut mag);
+        Ok(Self::from_parts(neg, mag))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some((last, rest)) = self.mag.split_last() else {
+            return f.pad_integral(true, "", "0");
+        };
+        let mut s = last.to_string();
+        for x in rest.iter().rev() {
+            s += &format!("{x:0BASE_DIGITS$}");
+        }
+        f.pad_integral(!self.neg, "", &s)
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.neg, other.neg) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::from_parts(!self.neg, self.mag)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.neg == rhs.neg {
+            return BigInt::from_parts(self.neg, add_mag(&self.mag, &rhs.mag));
+        }
+        // 絶対値の大きいほうから小さいほうを引く
+        match cmp_mag(&self.mag, &rhs.mag) {
+            Ordering::Less => {
+                let mut mag = rhs.mag.clone();
+                sub_mag_assign(&mut mag, &self.mag);
+                BigInt::from_parts(rhs.neg, mag)
+            }
+            _ => {
+                let mut mag = self.mag.clone();
+                sub_mag_assign(&mut mag, &rhs.mag);
+                BigInt::from_parts(self.neg, mag)
+            }
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(self.neg != rhs.neg, mul_mag(&self.mag, &rhs.mag))
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+macro_rules! impl_binop {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign:ident;)*) => {$(
+        impl $trait for BigInt {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self {
+                (&self).$method(&rhs)
+            }
+        }
+        impl $trait<&BigInt> for BigInt {
+            type Output = Self;
+            fn $method(self, rhs: &Self) -> Self {
+                (&self).$method(rhs)
+            }
+        }
+        impl $trait<BigInt> for &BigInt {
+            type Output = BigInt;
+            fn $method(self, rhs: BigInt) -> BigInt {
+                self.$method(&rhs)
+            }
+        }
+        impl $assign_trait for BigInt {
+            fn $assign(&mut self, rhs: Self) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+        impl $assign_trait<&BigInt> for BigInt {
+            fn $assign(&mut self, rhs: &Self) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+    )*};
+}
+
+impl_binop! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+}
@@ -0,0 +1,242 @@
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range, Shl, ShlAssign,
+    Shr, ShrAssign,
+};
+
+fn main() {
+    let mut a = BitSet::new(100);
+    a.set(3, true);
+    a.set(64, true);
+    a.set_range(10..20, true);
+    dbg!(a.count_ones(), a.get(3), a.get(4));
+    dbg!(a.ones().collect::<Vec<_>>());
+    dbg!((&a << 40).ones().collect::<Vec<_>>());
+    dbg!((&a >> 5).ones().collect::<Vec<_>>());
+    a.flip_range(15..70);
+    dbg!(a.count_range(0..64), a.next_one(20), (!&a).count_ones());
+
+    // 部分和問題: 重さ ws から作れる合計
+    let ws = [3, 5, 7];
+    let mut dp = BitSet::new(20);
+    dp.set(0, true);
+    for w in ws {
+        dp |= &(&dp << w);
+    }
+    dbg!(dp.ones().collect::<Vec<_>>());
+}
+
+/// 長さ固定の bitset．`u64` ごとに処理する
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitSet {
+    n: usize,
+    /// `n` 以上の位置のビットは常に `0`
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// `0` から `n - 1` までで，すべて `0`
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            words: vec![0; n.div_ceil(64)],
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.n
+    }
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+    /// 範囲外のビットを消す
+    fn fix_last(&mut self) {
+        if !self.n.is_multiple_of(64) {
+            *self.words.last_mut().unwrap() &= (1 << (self.n % 64)) - 1;
+        }
+    }
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.n);
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+    pub fn set(&mut self, i: usize, b: bool) {
+        assert!(i < self.n);
+        if b {
+            self.words[i / 64] |= 1 << (i % 64);
+        } else {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+    }
+    pub fn flip(&mut self, i: usize) {
+        assert!(i < self.n);
+        self.words[i / 64] ^= 1 << (i % 64);
+    }
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+    /// `range` の各ワードについて，`f(ワードの番号, そのワードで range に含まれる部分のマスク)`
+    fn for_each_mask(range: Range<usize>, mut f: impl FnMut(usize, u64)) {
+        let Range { start, end } = range;
+        if start >= end {
+            return;
+        }
+        let (first, last) = (start / 64, (end - 1) / 64);
+        for k in first..=last {
+            let lo = if k == first { start % 64 } else { 0 };
+            let hi = if k == last { (end - 1) % 64 + 1 } else { 64 };
+            let mask = (u64::MAX >> (64 - (hi - lo))) << lo;
+            f(k, mask);
+        }
+    }
+    pub fn set_range(&mut self, range: Range<usize>, b: bool) {
+        assert!(range.end <= self.n);
+        Self::for_each_mask(range, |k, mask| {
+            if b {
+                self.words[k] |= mask;
+            } else {
+                self.words[k] &= !mask;
+            }
+        });
+    }
+    pub fn flip_range(&mut self, range: Range<usize>) {
+        assert!(range.end <= self.n);
+        Self::for_each_mask(range, |k, mask| self.words[k] ^= mask);
+    }
+    pub fn count_range(&self, range: Range<usize>) -> usize {
+        assert!(range.end <= self.n);
+        let mut res = 0;
+        Self::for_each_mask(range, |k, mask| {
+            res += (self.words[k] & mask).count_ones() as usize
+        });
+        res
+    }
+    /// `i` 以上で最小の `1` の位置
+    pub fn next_one(&self, i: usize) -> Option<usize> {
+        if i >= self.n {
+            return None;
+        }
+        let mut k = i / 64;
+        let mut w = self.words[k] & (u64::MAX << (i % 64));
+        loop {
+            if w != 0 {
+                return Some(k * 64 + w.trailing_zeros() as usize);
+            }
+            k += 1;
+            w = *self.words.get(k)?;
+        }
+    }
+    /// `1` の位置を昇順に
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(k, &w)| {
+            let mut w = w;
+            std::iter::from_fn(move || {
+                (w != 0).then(|| {
+                    let i = w.trailing_zeros() as usize;
+                    w &= w - 1;
+                    k * 64 + i
+                })
+            })
+        })
+    }
+}
+
+impl ShlAssign<usize> for BitSet {
+    /// 位置 `i` のビットを `i + s` に．`n` 以上になったものは消える
+    fn shl_assign(&mut self, s: usize) {
+        let (q, r) = (s / 64, s % 64);
+        let len = self.words.len();
+        for k in (0..len).rev() {
+            let hi = if k >= q { self.words[k - q] << r } else { 0 };
+            let lo = if r != 0 && k > q {
+                self.words[k - q - 1] >> (64 - r)
+            } else {
+                0
+            };
+            self.words[k] = hi | lo;
+        }
+        self.fix_last();
+    }
+}
+
+impl ShrAssign<usize> for BitSet {
+    /// 位置 `i` のビットを `i - s` に．負になったものは消える
+    fn shr_assign(&mut self, s: usize) {
+        let (q, r) = (s / 64, s % 64);
+        let len = self.words.len();
+        for k in 0..len {
+            let lo = if k + q < len {
+                self.words[k + q] >> r
+            } else {
+                0
+            };
+            let hi = if r != 0 && k + q + 1 < len {
+                self.words[k + q + 1] << (64 - r)
+            } else {
+                0
+            };
+            self.words[k] = hi | lo;
+        }
+    }
+}
+
+impl Shl<usize> for &BitSet {
+    type Output = BitSet;
+    fn shl(self, s: usize) -> BitSet {
+        let mut res = self.clone();
+        res <<= s;
+        res
+    }
+}
+
+impl Shr<usize> for &BitSet {
+    type Output = BitSet;
+    fn shr(self, s: usize) -> BitSet {
+        let mut res = self.clone();
+        res >>= s;
+        res
+    }
+}
+
+impl Not for &BitSet {
+    type Output = BitSet;
+    fn not(self) -> BitSet {
+        let mut res = BitSet {
+            n: self.n,
+            words: self.words.iter().map(|w| !w).collect(),
+        };
+        res.fix_last();
+        res
+    }
+}
+
+macro_rules! impl_bitop {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign:ident, $op:tt;)*) => {$(
+        impl $assign_trait<&BitSet> for BitSet {
+            /// 長さが同じでなければならない
+            fn $assign(&mut self, rhs: &BitSet) {
+                assert_eq!(self.n, rhs.n);
+                for (a, b) in self.words.iter_mut().zip(&rhs.words) {
+                    *a $op *b;
+                }
+            }
+        }
+        impl $trait for &BitSet {
+            type Output = BitSet;
+            fn $method(self, rhs: &BitSet) -> BitSet {
+                let mut res = self.clone();
+                res.$assign(rhs);
+                res
+            }
+        }
+    )*};
+}
+
+impl_bitop! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &=;
+    BitOr, bitor, BitOrAssign, bitor_assign, |=;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^=;
+}
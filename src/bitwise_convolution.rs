@@ -0,0 +1,161 @@
+#[allow(dead_code)]
+#[path = "modint.rs"]
+pub mod modint;
+
+use modint::ModInt;
+
+fn main() {
+    type Mint = modint::ModInt998244353;
+    let a: Vec<Mint> = [1, 2, 3, 4].into_iter().map(Mint::new).collect();
+    let b: Vec<Mint> = [5, 6, 7, 8].into_iter().map(Mint::new).collect();
+    dbg!(or_convolution(&a, &b), and_convolution(&a, &b));
+    dbg!(xor_convolution(&a, &b), xor_convolution_naive(&a, &b));
+    // 長さが違ってもよい
+    dbg!(xor_convolution(&a, &b[..3]));
+
+    // 部分集合の和
+    let mut f = a.clone();
+    subset_zeta(&mut f);
+    dbg!(&f);
+    subset_mobius(&mut f);
+    dbg!(&f);
+}
+
+/// `f[S] ← Σ_{T ⊆ S} f[T]`．長さは 2 べき
+pub fn subset_zeta<const M: u32>(f: &mut [ModInt<M>]) {
+    let n = f.len();
+    assert!(n.is_power_of_two());
+    let mut w = 1;
+    while w < n {
+        for i in 0..n {
+            if i & w != 0 {
+                let x = f[i ^ w];
+                f[i] += x;
+            }
+        }
+        w <<= 1;
+    }
+}
+
+/// `subset_zeta` の逆
+pub fn subset_mobius<const M: u32>(f: &mut [ModInt<M>]) {
+    let n = f.len();
+    assert!(n.is_power_of_two());
+    let mut w = 1;
+    while w < n {
+        for i in 0..n {
+            if i & w != 0 {
+                let x = f[i ^ w];
+                f[i] -= x;
+            }
+        }
+        w <<= 1;
+    }
+}
+
+/// `f[S] ← Σ_{T ⊇ S} f[T]`．長さは 2 べき
+pub fn superset_zeta<const M: u32>(f: &mut [ModInt<M>]) {
+    let n = f.len();
+    assert!(n.is_power_of_two());
+    let mut w = 1;
+    while w < n {
+        for i in 0..n {
+            if i & w == 0 {
+                let x = f[i | w];
+                f[i] += x;
+            }
+        }
+        w <<= 1;
+    }
+}
+
+/// `superset_zeta` の逆
+pub fn superset_mobius<const M: u32>(f: &mut [ModInt<M>]) {
+    let n = f.len();
+    assert!(n.is_power_of_two());
+    let mut w = 1;
+    while w < n {
+        for i in 0..n {
+            if i & w == 0 {
+                let x = f[i | w];
+                f[i] -= x;
+            }
+        }
+        w <<= 1;
+    }
+}
+
+/// `f[S] ← Σ_T (-1)^{|S ∩ T|} f[T]`．`inv` なら逆変換 (`n` で割る)．長さは 2 べき
+pub fn walsh_hadamard<const M: u32>(f: &mut [ModInt<M>], inv: bool) {
+    let n = f.len();
+    assert!(n.is_power_of_two());
+    let mut w = 1;
+    while w < n {
+        for i in 0..n {
+            if i & w == 0 {
+                let (x, y) = (f[i], f[i | w]);
+                f[i] = x + y;
+                f[i | w] = x - y;
+            }
+        }
+        w <<= 1;
+    }
+    if inv {
+        let n_inv = ModInt::from(n).inv();
+        for x in f {
+            *x *= n_inv;
+        }
+    }
+}
+
+/// 長さを 2 べきにそろえて，変換 `t` したものの各点積を逆変換 `t_inv` する
+fn convolve<const M: u32>(
+    a: &[ModInt<M>],
+    b: &[ModInt<M>],
+    t: impl Fn(&mut [ModInt<M>]),
+    t_inv: impl Fn(&mut [ModInt<M>]),
+) -> Vec<ModInt<M>> {
+    let n = a.len().max(b.len()).next_power_of_two();
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.resize(n, ModInt::raw(0));
+    b.resize(n, ModInt::raw(0));
+    t(&mut a);
+    t(&mut b);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x *= *y;
+    }
+    t_inv(&mut a);
+    a
+}
+
+/// `c[k] = Σ_{i | j = k} a[i] b[j]`．長さは `max(|a|, |b|)` 以上の最小の 2 べき
+pub fn or_convolution<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    convolve(a, b, subset_zeta, subset_mobius)
+}
+
+/// `c[k] = Σ_{i & j = k} a[i] b[j]`
+pub fn and_convolution<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    convolve(a, b, superset_zeta, superset_mobius)
+}
+
+/// `c[k] = Σ_{i ^ j = k} a[i] b[j]`．長さが `M` の倍数だと逆変換できない
+pub fn xor_convolution<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    convolve(
+        a,
+        b,
+        |f| walsh_hadamard(f, false),
+        |f| walsh_hadamard(f, true),
+    )
+}
+
+pub fn xor_convolution_naive<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    let n = a.len().max(b.len()).next_power_of_two();
+    let mut c = vec![ModInt::raw(0); n];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            c[i ^ j] += x * y;
+        }
+    }
+    c
+}
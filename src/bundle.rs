@@ -0,0 +1,190 @@
+use std::fs;
+use std::path::Path;
+
+/// `bundle solution.rs > main.rs`
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 2 {
+        eprintln!("usage: {} <solution.rs>", args[0]);
+        std::process::exit(1);
+    }
+    print!("{}", bundle(Path::new(&args[1]), true));
+}
+
+/// `#[path = "..."] mod name;` を再帰的に `mod name { ... }` に展開する
+///
+/// `#[cfg(test)]` のついた要素と doc comment を除く．展開したファイル (`root` でないもの) からは `fn main` も除く．
+pub fn bundle(path: &Path, root: bool) -> String {
+    let src = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let src = strip(&src, root);
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let mut res = String::new();
+    // 直前の #[path = "..."]
+    let mut mod_path: Option<String> = None;
+    for line in src.lines() {
+        let t = line.trim();
+        if let Some(p) = t
+            .strip_prefix("#[path = \"")
+            .and_then(|p| p.strip_suffix("\"]"))
+        {
+            mod_path = Some(p.to_string());
+            continue;
+        }
+        if let Some(p) = &mod_path {
+            if let Some(decl) = mod_decl(t) {
+                let indent = &line[..line.len() - line.trim_start().len()];
+                // fn main だけが使っていた use が残る
+                res += &format!("{}#[allow(unused_imports)]\n", indent);
+                res += &format!("{}{} {{\n", indent, decl);
+                res += &bundle(&dir.join(p), false);
+                res += &format!("{}}}\n", indent);
+                mod_path = None;
+                continue;
+            }
+            // 属性が #[path] と mod の間にある
+            if !t.starts_with("#[") {
+                panic!("#[path] must be followed by a module declaration: {}", t);
+            }
+        }
+        res += line;
+        res += "\n";
+    }
+    res
+}
+
+/// `mod name;` や `pub mod name;` なら `mod name` や `pub mod name` を返す
+fn mod_decl(t: &str) -> Option<&str> {
+    let decl = t.strip_suffix(';')?;
+    let name = decl
+        .strip_prefix("mod ")
+        .or_else(|| decl.strip_prefix("pub mod "))
+        .or_else(|| decl.strip_prefix("pub(crate) mod "))?;
+    name.bytes()
+        .all(|c| c.is_ascii_alphanumeric() || c == b'_')
+        .then_some(decl)
+}
+
+/// doc comment と `#[cfg(test)]` のついた要素，`root` でなければ `fn main` を除く
+fn strip(src: &str, root: bool) -> String {
+    let s = src.as_bytes();
+    let mut res = String::new();
+    let mut i = 0;
+    while i < s.len() {
+        let end = src[i..].find('\n').map_or(s.len(), |k| i + k + 1);
+        let line = &src[i..end];
+        let t = line.trim();
+        if t.starts_with("///") || t.starts_with("//!") {
+            i = end;
+        } else if t == "#[cfg(test)]" {
+            // 次の要素の終わりまで飛ばす
+            i = item_end(s, end);
+        } else if !root && line.starts_with("fn main()") {
+            i = item_end(s, i);
+        } else {
+            res += line;
+            i = end;
+        }
+    }
+    res
+}
+
+/// `s[i..]` から始まる要素の終わり (`;` またはブロックの `}` の次の行の先頭)
+fn item_end(s: &[u8], mut i: usize) -> usize {
+    let mut depth = 0;
+    while i < s.len() {
+        match s[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            b';' if depth == 0 => break,
+            _ => {}
+        }
+        i = skip_literal(s, i);
+    }
+    // 行末まで
+    while i < s.len() && s[i] != b'\n' {
+        i += 1;
+    }
+    (i + 1).min(s.len())
+}
+
+/// `s[i]` が文字列，文字，コメントの始まりならその次の位置，そうでなければ `i + 1`
+fn skip_literal(s: &[u8], i: usize) -> usize {
+    let ident = |c: u8| c.is_ascii_alphanumeric() || c == b'_';
+    match s[i] {
+        b'/' if s.get(i + 1) == Some(&b'/') => {
+            let mut j = i;
+            while j < s.len() && s[j] != b'\n' {
+                j += 1;
+            }
+            j
+        }
+        b'/' if s.get(i + 1) == Some(&b'*') => {
+            // ネストしうる
+            let (mut j, mut depth) = (i + 2, 1);
+            while j < s.len() && depth > 0 {
+                if s[j..].starts_with(b"/*") {
+                    depth += 1;
+                    j += 2;
+                } else if s[j..].starts_with(b"*/") {
+                    depth -= 1;
+                    j += 2;
+                } else {
+                    j += 1;
+                }
+            }
+            j
+        }
+        b'"' => {
+            let mut j = i + 1;
+            while j < s.len() && s[j] != b'"' {
+                j += if s[j] == b'\\' { 2 } else { 1 };
+            }
+            j + 1
+        }
+        // r"...", r#"..."#, br#"..."#
+        b'r' if i == 0 || !ident(s[i - 1]) || (s[i - 1] == b'b' && (i < 2 || !ident(s[i - 2]))) => {
+            let mut j = i + 1;
+            while j < s.len() && s[j] == b'#' {
+                j += 1;
+            }
+            if s.get(j) != Some(&b'"') {
+                return i + 1;
+            }
+            let mut close = vec![b'"'];
+            close.resize(j - i, b'#');
+            match s[j + 1..].windows(close.len()).position(|w| w == close) {
+                Some(k) => j + 1 + k + close.len(),
+                None => s.len(),
+            }
+        }
+        b'\'' => {
+            // 文字リテラルか lifetime か
+            if s.get(i + 1) == Some(&b'\\') {
+                let mut j = i + 2;
+                while j < s.len() && s[j] != b'\'' {
+                    j += 1;
+                }
+                return j + 1;
+            }
+            let len = match s.get(i + 1) {
+                Some(&c) if c < 0x80 => 1,
+                Some(&c) if c >= 0xf0 => 4,
+                Some(&c) if c >= 0xe0 => 3,
+                Some(_) => 2,
+                None => return i + 1,
+            };
+            if s.get(i + 1 + len) == Some(&b'\'') {
+                i + 2 + len
+            } else {
+                i + 1
+            }
+        }
+        _ => i + 1,
+    }
+}
@@ -0,0 +1,91 @@
+#[allow(dead_code)]
+#[path = "modint.rs"]
+pub mod modint;
+
+use modint::ModInt;
+
+fn main() {
+    let comb = Comb::<998244353>::new(100);
+    dbg!(comb.binom(10, 3), comb.binom(3, 10), comb.perm(10, 3));
+    dbg!(comb.multiset(3, 2), comb.multiset(0, 0));
+    dbg!((0..8).map(|n| comb.catalan(n)).collect::<Vec<_>>());
+    dbg!(
+        comb.fact(10),
+        comb.fact_inv(10) * comb.fact(10),
+        comb.inv(7) * ModInt::new(7)
+    );
+
+    // 法が小さい素数で n が大きい場合
+    let comb = Comb::<7>::new(6);
+    dbg!(comb.lucas(1_000_000_000_000_000_000, 123_456_789_012));
+    dbg!(comb.lucas(10, 3)); // 120 mod 7
+}
+
+/// 階乗とその逆元のテーブル．`M` は `n` より大きい素数
+pub struct Comb<const M: u32> {
+    fact: Vec<ModInt<M>>,
+    fact_inv: Vec<ModInt<M>>,
+}
+
+impl<const M: u32> Comb<M> {
+    /// `0..=n` の階乗を求める
+    pub fn new(n: usize) -> Self {
+        assert!(n < M as usize);
+        let mut fact = vec![ModInt::new(1); n + 1];
+        for i in 1..=n {
+            fact[i] = fact[i - 1] * ModInt::from(i);
+        }
+        let mut fact_inv = vec![fact[n].inv(); n + 1];
+        for i in (1..=n).rev() {
+            fact_inv[i - 1] = fact_inv[i] * ModInt::from(i);
+        }
+        Self { fact, fact_inv }
+    }
+    pub fn fact(&self, n: usize) -> ModInt<M> {
+        self.fact[n]
+    }
+    pub fn fact_inv(&self, n: usize) -> ModInt<M> {
+        self.fact_inv[n]
+    }
+    /// `1 / n`．`1 <= n`
+    pub fn inv(&self, n: usize) -> ModInt<M> {
+        self.fact_inv[n] * self.fact[n - 1]
+    }
+    /// `n` 個から `k` 個選ぶ方法の数．`k > n` なら `0`
+    pub fn binom(&self, n: usize, k: usize) -> ModInt<M> {
+        if k > n {
+            return ModInt::raw(0);
+        }
+        self.fact[n] * self.fact_inv[k] * self.fact_inv[n - k]
+    }
+    /// `n` 個から `k` 個選んで並べる方法の数
+    pub fn perm(&self, n: usize, k: usize) -> ModInt<M> {
+        if k > n {
+            return ModInt::raw(0);
+        }
+        self.fact[n] * self.fact_inv[n - k]
+    }
+    /// `n` 種類から重複を許して `k` 個選ぶ方法の数
+    pub fn multiset(&self, n: usize, k: usize) -> ModInt<M> {
+        if n == 0 {
+            return ModInt::new((k == 0) as u32);
+        }
+        self.binom(n + k - 1, k)
+    }
+    /// Catalan 数．`2n` まで求めてある必要がある
+    pub fn catalan(&self, n: usize) -> ModInt<M> {
+        self.binom(2 * n, n) - self.binom(2 * n, n + 1)
+    }
+    /// Lucas の定理による二項係数．`Comb::new(M - 1)` で作ってある必要がある
+    pub fn lucas(&self, mut n: u64, mut k: u64) -> ModInt<M> {
+        assert_eq!(self.fact.len(), M as usize);
+        let mut res = ModInt::new(1);
+        while k > 0 {
+            let (ni, ki) = ((n % M as u64) as usize, (k % M as u64) as usize);
+            res *= self.binom(ni, ki);
+            n /= M as u64;
+            k /= M as u64;
+        }
+        res
+    }
+}
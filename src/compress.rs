@@ -0,0 +1,66 @@
+fn main() {
+    let cmp: Compress<i64> = [100, -5, 100, 30, 7].into_iter().collect();
+    dbg!(cmp.len(), cmp.index(&30), cmp.value(1), cmp.get(&8));
+    dbg!(cmp.lower_bound(&8), cmp.lower_bound(&1000));
+
+    let (idx, cmp) = compress(&["b", "a", "c", "a"]);
+    dbg!(idx, cmp.values());
+}
+
+/// 座標圧縮．値を昇順に `0, 1, ...` に対応させる
+#[derive(Clone, Debug)]
+pub struct Compress<T> {
+    values: Vec<T>,
+}
+
+impl<T: Ord> Compress<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut values: Vec<T> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+    /// 異なる値の個数
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    /// `x` の番号．なければ panic
+    pub fn index(&self, x: &T) -> usize {
+        self.get(x).expect("value not found")
+    }
+    /// `x` の番号．なければ `None`
+    pub fn get(&self, x: &T) -> Option<usize> {
+        self.values.binary_search(x).ok()
+    }
+    /// `x` 未満の値の個数．`x` がなくてもよい
+    pub fn lower_bound(&self, x: &T) -> usize {
+        self.values.partition_point(|v| v < x)
+    }
+    /// 番号 `i` の値
+    pub fn value(&self, i: usize) -> &T {
+        &self.values[i]
+    }
+    /// 値を昇順に
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+    /// 各要素の番号
+    pub fn indices(&self, a: &[T]) -> Vec<usize> {
+        a.iter().map(|x| self.index(x)).collect()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Compress<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// `a` の各要素の番号と，`a` の値で作った `Compress`
+pub fn compress<T: Ord + Clone>(a: &[T]) -> (Vec<usize>, Compress<T>) {
+    let cmp = Compress::new(a.iter().cloned());
+    (cmp.indices(a), cmp)
+}
@@ -0,0 +1,71 @@
+#[allow(dead_code)]
+#[path = "geometry.rs"]
+pub mod geometry;
+
+use geometry::Point;
+
+fn main() {
+    let ps: Vec<Point<i64>> = [
+        (0, 0),
+        (2, 0),
+        (4, 0),
+        (4, 4),
+        (2, 2),
+        (0, 4),
+        (1, 3),
+        (0, 2),
+    ]
+    .into_iter()
+    .map(|(x, y)| Point::new(x, y))
+    .collect();
+    dbg!(convex_hull(&ps, false));
+    dbg!(convex_hull(&ps, true));
+    // 一直線上
+    let line: Vec<Point<i64>> = (0..4).map(|i| Point::new(i, 2 * i)).collect();
+    dbg!(convex_hull(&line, false), convex_hull(&line, true));
+    dbg!(convex_hull(&[Point::new(1, 1), Point::new(1, 1)], false));
+}
+
+/// 凸包の頂点を，`(x, y)` が辞書順最小の点から反時計回りに．重複する点は 1 つにまとめる
+///
+/// `keep_collinear` なら辺上の点も含める．すべての点が一直線上にあるときは，端点 (`keep_collinear` なら間の点も) を辞書順に返す．
+pub fn convex_hull(ps: &[Point<i64>], keep_collinear: bool) -> Vec<Point<i64>> {
+    let mut ps = ps.to_vec();
+    ps.sort_unstable();
+    ps.dedup();
+    let n = ps.len();
+    if n <= 2 {
+        return ps;
+    }
+    if ps.iter().all(|&p| (ps[1] - ps[0]).cross(p - ps[0]) == 0) {
+        return if keep_collinear {
+            ps
+        } else {
+            vec![ps[0], ps[n - 1]]
+        };
+    }
+    // 右に曲がる (keep_collinear でなければまっすぐ進むのも) なら捨てる
+    let bad = |a: Point<i64>, b: Point<i64>, c: Point<i64>| {
+        let cross = (b - a).cross(c - a);
+        cross < 0 || cross == 0 && !keep_collinear
+    };
+    let mut hull: Vec<Point<i64>> = vec![];
+    // 下側を左から右へ
+    for &p in &ps {
+        while hull.len() >= 2 && bad(hull[hull.len() - 2], hull[hull.len() - 1], p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // 上側を右から左へ．下側の点は消さない
+    let lower_len = hull.len();
+    for &p in ps.iter().rev().skip(1) {
+        while hull.len() > lower_len && bad(hull[hull.len() - 2], hull[hull.len() - 1], p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // 最後に最初の点に戻ってくる
+    hull.pop();
+    hull
+}
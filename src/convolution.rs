@@ -0,0 +1,220 @@
+#[allow(dead_code)]
+#[path = "modint.rs"]
+pub mod modint;
+
+use modint::ModInt;
+
+fn main() {
+    type Mint = modint::ModInt998244353;
+    let a: Vec<Mint> = [1, 2, 3, 4].into_iter().map(Mint::new).collect();
+    let b: Vec<Mint> = [5, 6, 7, 8, 9].into_iter().map(Mint::new).collect();
+    dbg!(convolution(&a, &b));
+    dbg!(convolution_naive(&a, &b));
+
+    let n = 1 << 19;
+    let a: Vec<Mint> = (0..n).map(|i| Mint::new(i as u32)).collect();
+    let c = convolution(&a, &a);
+    dbg!(c.len(), c[n - 1], c[2 * n - 2]);
+
+    // 法が 167772161 = 5 * 2^25 + 1 でも使える
+    let a: Vec<ModInt<167772161>> = (1..=3).map(ModInt::new).collect();
+    dbg!(convolution(&a, &a));
+    dbg!(primitive_root(998244353), primitive_root(167772161));
+
+    // 任意の法，整数
+    dbg!(convolution_mod(
+        &[1_000_000_006, 2],
+        &[1_000_000_006, 3],
+        1_000_000_007
+    ));
+    dbg!(convolution_i64(&[-1_000_000_000, 3], &[1_000_000_000, -4]));
+    let a = vec![1_000_000_006; 100_000];
+    dbg!(convolution_mod(&a, &a, 1_000_000_007)[99_999]);
+}
+
+/// 素数 `m` の原始根のうち最小のもの
+pub fn primitive_root(m: u32) -> u32 {
+    if m == 2 {
+        return 1;
+    }
+    let mut factors = vec![];
+    let mut x = m - 1;
+    let mut p = 2;
+    while p * p <= x {
+        if x.is_multiple_of(p) {
+            factors.push(p);
+            while x.is_multiple_of(p) {
+                x /= p;
+            }
+        }
+        p += 1;
+    }
+    if x > 1 {
+        factors.push(x);
+    }
+    let pow = |mut a: u64, mut e: u64| {
+        let mut res = 1;
+        while e > 0 {
+            if e & 1 == 1 {
+                res = res * a % m as u64;
+            }
+            a = a * a % m as u64;
+            e >>= 1;
+        }
+        res
+    };
+    (2..)
+        .find(|&g| factors.iter().all(|&p| pow(g, ((m - 1) / p) as u64) != 1))
+        .unwrap() as u32
+}
+
+/// 長さ `n` の `ω^j` (`j < n / 2`)．`ω` は1の原始 `n` 乗根 (`inv` なら逆数)
+fn roots<const M: u32>(n: usize, inv: bool) -> Vec<ModInt<M>> {
+    assert_eq!(
+        (M - 1) as usize % n,
+        0,
+        "length {n} is too long for modulus {M}"
+    );
+    let mut w = ModInt::<M>::new(primitive_root(M)).pow(((M - 1) as usize / n) as u64);
+    if inv {
+        w = w.inv();
+    }
+    let mut res = Vec::with_capacity(n / 2);
+    let mut now = ModInt::raw(1);
+    for _ in 0..n / 2 {
+        res.push(now);
+        now *= w;
+    }
+    res
+}
+
+/// 長さが2冪の `a` を，各 `ω^j` での値に変換する．結果はビット反転した順に並ぶ
+pub fn ntt<const M: u32>(a: &mut [ModInt<M>]) {
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    let roots = roots(n, false);
+    let mut len = n / 2;
+    while len > 0 {
+        let step = n / (2 * len);
+        for block in a.chunks_exact_mut(2 * len) {
+            let (l, r) = block.split_at_mut(len);
+            for (j, (x, y)) in l.iter_mut().zip(r).enumerate() {
+                let (u, v) = (*x, *y);
+                *x = u + v;
+                *y = (u - v) * roots[j * step];
+            }
+        }
+        len /= 2;
+    }
+}
+
+/// `ntt` の逆変換．`ntt` の結果と同じくビット反転した順で受け取る
+pub fn intt<const M: u32>(a: &mut [ModInt<M>]) {
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    let roots = roots(n, true);
+    let mut len = 1;
+    while len < n {
+        let step = n / (2 * len);
+        for block in a.chunks_exact_mut(2 * len) {
+            let (l, r) = block.split_at_mut(len);
+            for (j, (x, y)) in l.iter_mut().zip(r).enumerate() {
+                let (u, v) = (*x, *y * roots[j * step]);
+                *x = u + v;
+                *y = u - v;
+            }
+        }
+        len *= 2;
+    }
+    let n_inv = ModInt::<M>::from(n).inv();
+    a.iter_mut().for_each(|x| *x *= n_inv);
+}
+
+/// `c[k] = Σ_{i + j = k} a[i] b[j]`．`M` は素数で，`M - 1` が結果の長さ以上の2冪で割り切れる必要がある
+pub fn convolution<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    if a.len().min(b.len()) <= 60 {
+        return convolution_naive(a, b);
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    let mut fa = a.to_vec();
+    fa.resize(n, ModInt::raw(0));
+    let mut fb = b.to_vec();
+    fb.resize(n, ModInt::raw(0));
+    ntt(&mut fa);
+    ntt(&mut fb);
+    fa.iter_mut().zip(&fb).for_each(|(x, y)| *x *= *y);
+    intt(&mut fa);
+    fa.truncate(len);
+    fa
+}
+
+pub fn convolution_naive<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut c = vec![ModInt::raw(0); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            c[i + j] += x * y;
+        }
+    }
+    c
+}
+
+const P1: u32 = 167772161;
+const P2: u32 = 469762049;
+const P3: u32 = 754974721;
+
+/// 3つの素数での畳み込みから，中国剰余定理で `0..P1 P2 P3` の値を復元する
+fn convolution_crt(a: &[i64], b: &[i64]) -> Vec<u128> {
+    fn conv<const M: u32>(a: &[i64], b: &[i64]) -> Vec<ModInt<M>> {
+        let a: Vec<ModInt<M>> = a.iter().map(|&x| x.into()).collect();
+        let b: Vec<ModInt<M>> = b.iter().map(|&x| x.into()).collect();
+        convolution(&a, &b)
+    }
+    let c1 = conv::<P1>(a, b);
+    let c2 = conv::<P2>(a, b);
+    let c3 = conv::<P3>(a, b);
+    let p1_inv = ModInt::<P2>::new(P1).inv();
+    let p1p2_inv = (ModInt::<P3>::new(P1) * ModInt::new(P2)).inv();
+    c1.iter()
+        .zip(&c2)
+        .zip(&c3)
+        .map(|((x1, x2), x3)| {
+            // Garner のアルゴリズム
+            let t1 = x1.val();
+            let t2 = ((ModInt::<P2>::new(x2.val()) - ModInt::new(t1)) * p1_inv).val();
+            let t3 = ((*x3 - ModInt::new(t1) - ModInt::new(t2) * ModInt::new(P1)) * p1p2_inv).val();
+            t1 as u128 + t2 as u128 * P1 as u128 + t3 as u128 * P1 as u128 * P2 as u128
+        })
+        .collect()
+}
+
+/// 任意の法 `m` での畳み込み．長さは `2^24` 以下で，`min(|a|, |b|) (m - 1)^2 < 5 * 10^25` の必要がある
+pub fn convolution_mod(a: &[u32], b: &[u32], m: u32) -> Vec<u32> {
+    let a: Vec<i64> = a.iter().map(|&x| (x % m) as i64).collect();
+    let b: Vec<i64> = b.iter().map(|&x| (x % m) as i64).collect();
+    convolution_crt(&a, &b)
+        .into_iter()
+        .map(|x| (x % m as u128) as u32)
+        .collect()
+}
+
+/// 整数の畳み込み．結果の各項が `i64` に収まる必要がある
+pub fn convolution_i64(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let p = P1 as u128 * P2 as u128 * P3 as u128;
+    convolution_crt(a, b)
+        .into_iter()
+        .map(|x| {
+            if x > p / 2 {
+                (x as i128 - p as i128) as i64
+            } else {
+                x as i64
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,85 @@
+fn main() {
+    dbg!(ext_gcd(240, 46), ext_gcd(-6, 4), ext_gcd(0, 0));
+    dbg!(inv_mod(3, 7), inv_mod(-3, 7), inv_mod(4, 6));
+    dbg!(crt(&[2, 3, 2], &[3, 5, 7]));
+    dbg!(crt(&[1, 3], &[4, 6]), crt(&[1, 2], &[4, 6]));
+    dbg!(crt(&[], &[]));
+    // 3 つの NTT 素数での値から，10^9 + 7 での値を復元する
+    let x: u128 = 123_456_789_012_345_678_901_234;
+    let mods = [167772161, 469762049, 754974721];
+    let rems: Vec<i64> = mods.iter().map(|&m| (x % m as u128) as i64).collect();
+    dbg!(garner(&rems, &mods, 1_000_000_007), x % 1_000_000_007);
+}
+
+/// `ax + by = g = gcd(a, b)` となる `(g, x, y)`．`g >= 0`
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut a, mut b) = (a, b);
+    let (mut x0, mut y0, mut x1, mut y1) = (1, 0, 0, 1);
+    while b != 0 {
+        let q = a / b;
+        (a, b) = (b, a - q * b);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if a < 0 {
+        (-a, -x0, -y0)
+    } else {
+        (a, x0, y0)
+    }
+}
+
+/// `ax ≡ 1 (mod m)` となる `0 <= x < m`．`gcd(a, m) != 1` なら `None`
+pub fn inv_mod(a: i64, m: i64) -> Option<i64> {
+    assert!(m >= 1);
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// すべての `i` について `x ≡ rems[i] (mod mods[i])` となる `x` を `(x mod lcm, lcm)` で．解がなければ `None`
+///
+/// 法は互いに素でなくてもよい．`lcm` は `i64` に収まる必要がある．
+pub fn crt(rems: &[i64], mods: &[i64]) -> Option<(i64, i64)> {
+    assert_eq!(rems.len(), mods.len());
+    // x ≡ r (mod m)
+    let (mut r, mut m) = (0i64, 1i64);
+    for (&r1, &m1) in rems.iter().zip(mods) {
+        assert!(m1 >= 1);
+        let r1 = r1.rem_euclid(m1);
+        // r + m t ≡ r1 (mod m1) を t について解く
+        let (g, p, _) = ext_gcd(m, m1);
+        if (r1 - r) % g != 0 {
+            return None;
+        }
+        let m1_g = m1 / g;
+        let t = ((r1 - r) / g) as i128 * p as i128 % m1_g as i128;
+        let lcm = m as i128 * m1_g as i128;
+        r = ((r as i128 + m as i128 * t).rem_euclid(lcm)) as i64;
+        m = lcm as i64;
+    }
+    Some((r, m))
+}
+
+/// 法が互いに素なとき，`x ≡ rems[i] (mod mods[i])` となる最小の `x >= 0` を `modulus` で割った余り
+///
+/// `x` 自体が大きすぎて表せない場合にも使える．`O(k^2)`
+pub fn garner(rems: &[i64], mods: &[i64], modulus: i64) -> i64 {
+    assert_eq!(rems.len(), mods.len());
+    let k = rems.len();
+    // x = t[0] + t[1] m[0] + t[2] m[0] m[1] + ...
+    // prod[j]: m[0] ... m[i - 1] mod mods[j] (j == k なら modulus)
+    // sum[j]: t[0] + ... + t[i - 1] m[0] ... m[i - 2] mod mods[j]
+    let mut prod: Vec<i64> = mods.iter().chain([&modulus]).map(|&m| 1 % m).collect();
+    let mut sum = vec![0i64; k + 1];
+    for i in 0..k {
+        let m = mods[i];
+        let t = ((rems[i].rem_euclid(m) - sum[i]).rem_euclid(m) as i128
+            * inv_mod(prod[i], m).expect("moduli must be pairwise coprime") as i128
+            % m as i128) as i64;
+        for j in i + 1..=k {
+            let mj = if j == k { modulus } else { mods[j] };
+            sum[j] = ((sum[j] as i128 + t as i128 * prod[j] as i128) % mj as i128) as i64;
+            prod[j] = (prod[j] as i128 * m as i128 % mj as i128) as i64;
+        }
+    }
+    sum[k]
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+fn main() {
+    dbg!(
+        discrete_log(2, 1, 5),
+        discrete_log(2, 3, 5),
+        discrete_log(2, 4, 5)
+    );
+    dbg!(
+        discrete_log(3, 13, 17),
+        discrete_log(2, 0, 8),
+        discrete_log(2, 6, 10)
+    );
+    dbg!(
+        discrete_log(4, 2, 6),
+        discrete_log(0, 1, 1),
+        discrete_log(0, 0, 7)
+    );
+    dbg!(discrete_log(3, 123_456_789, 1_000_000_007));
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// `a^x ≡ b (mod m)` となる最小の `x >= 0`．なければ `None`．`O(√m)`
+pub fn discrete_log(a: u64, b: u64, m: u64) -> Option<u64> {
+    assert!(m >= 1);
+    let (mut a, mut b, mut m) = (a % m, b % m, m);
+    // a と m が互いに素になるまで両辺を gcd で割る．k a^{x - add} ≡ b (mod m) の形にする
+    let mut k = 1 % m;
+    let mut add = 0;
+    loop {
+        if k == b {
+            return Some(add);
+        }
+        let g = gcd(a, m);
+        if g == 1 {
+            break;
+        }
+        if b % g != 0 {
+            return None;
+        }
+        b /= g;
+        m /= g;
+        add += 1;
+        k = mul_mod(k, a / g, m);
+        a %= m;
+        b %= m;
+    }
+    // baby-step giant-step: x = in - j (1 <= i <= n, 0 <= j < n)
+    let n = (m as f64).sqrt() as u64 + 1;
+    let mut baby = HashMap::new();
+    let mut cur = b;
+    for j in 0..n {
+        // 同じ値なら j が大きいほうが x が小さい
+        baby.insert(cur, j);
+        cur = mul_mod(cur, a, m);
+    }
+    let an = (0..n).fold(1 % m, |acc, _| mul_mod(acc, a, m));
+    let mut cur = k;
+    for i in 1..=n {
+        cur = mul_mod(cur, an, m);
+        if let Some(&j) = baby.get(&cur) {
+            return Some(add + i * n - j);
+        }
+    }
+    None
+}
@@ -0,0 +1,105 @@
+fn main() {
+    let graph = vec![
+        vec![1, 2],
+        vec![3],
+        vec![3, 4],
+        vec![5],
+        vec![5],
+        vec![1],
+        vec![0],
+        vec![],
+    ];
+    let idom = dominator_tree(&graph, 0);
+    dbg!(&idom);
+    // 0 から 5 への任意のパスが通る頂点
+    let mut v = 5;
+    while let Some(p) = idom[v].filter(|&p| p != v) {
+        v = p;
+        dbg!(v);
+    }
+}
+
+/// 各頂点の immediate dominator．`root` は `Some(root)`，`root` から到達できない頂点は `None`．
+pub fn dominator_tree(graph: &[Vec<usize>], root: usize) -> Vec<Option<usize>> {
+    const NONE: usize = usize::MAX;
+    let n = graph.len();
+    assert!(root < n, "index out: {}/{}", root, n);
+
+    // DFS 順に番号を振り直す
+    let mut id = vec![NONE; n];
+    let mut vertex = vec![];
+    let mut parent = vec![];
+    let mut stack = vec![(root, NONE)];
+    while let Some((v, p)) = stack.pop() {
+        if id[v] != NONE {
+            continue;
+        }
+        id[v] = vertex.len();
+        vertex.push(v);
+        parent.push(p);
+        for &to in graph[v].iter().rev() {
+            if id[to] == NONE {
+                stack.push((to, id[v]));
+            }
+        }
+    }
+    let k = vertex.len();
+    let mut rev = vec![vec![]; k];
+    for (v, to) in graph.iter().enumerate() {
+        if id[v] == NONE {
+            continue;
+        }
+        for &to in to {
+            rev[id[to]].push(id[v]);
+        }
+    }
+
+    let mut semi: Vec<usize> = (0..k).collect();
+    let mut idom = vec![0; k];
+    let mut ancestor = vec![NONE; k];
+    let mut label: Vec<usize> = (0..k).collect();
+    let mut bucket = vec![vec![]; k];
+    let mut path = vec![];
+    let mut eval = |v: usize, semi: &[usize], ancestor: &mut [usize], label: &mut [usize]| {
+        if ancestor[v] == NONE {
+            return v;
+        }
+        let mut x = v;
+        while ancestor[ancestor[x]] != NONE {
+            path.push(x);
+            x = ancestor[x];
+        }
+        while let Some(y) = path.pop() {
+            let a = ancestor[y];
+            if semi[label[a]] < semi[label[y]] {
+                label[y] = label[a];
+            }
+            ancestor[y] = ancestor[a];
+        }
+        label[v]
+    };
+    for w in (1..k).rev() {
+        for &v in &rev[w] {
+            let u = eval(v, &semi, &mut ancestor, &mut label);
+            semi[w] = semi[w].min(semi[u]);
+        }
+        bucket[semi[w]].push(w);
+        let p = parent[w];
+        ancestor[w] = p;
+        for v in std::mem::take(&mut bucket[p]) {
+            let u = eval(v, &semi, &mut ancestor, &mut label);
+            idom[v] = if semi[u] < semi[v] { u } else { p };
+        }
+    }
+    for w in 1..k {
+        if idom[w] != semi[w] {
+            idom[w] = idom[idom[w]];
+        }
+    }
+
+    let mut res = vec![None; n];
+    for w in 0..k {
+        res[vertex[w]] = Some(vertex[idom[w]]);
+    }
+    res
+}
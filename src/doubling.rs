@@ -0,0 +1,118 @@
+fn main() {
+    //   0 -> 1 -> 2 -> 3 -> 4 -> 2
+    let next = vec![1, 2, 3, 4, 2];
+    let weight = vec![1, 10, 100, 1000, 10000];
+    let db = Doubling::<AddU64>::new(&next, &weight);
+    dbg!(db.kth_successor(0, 1_000_000_000_000_000_000));
+    dbg!(db.prod(0, 4));
+    dbg!(db.prod(1, 0));
+    dbg!(rho(&next, 0));
+
+    let db = Doubling::<NoWeight>::from_next(&next);
+    dbg!(db.kth_successor(3, 5));
+}
+
+pub trait DoublingType {
+    type Item: Clone;
+    fn id() -> Self::Item;
+    fn prod(a: &Self::Item, b: &Self::Item) -> Self::Item;
+}
+
+pub enum NoWeight {}
+
+impl DoublingType for NoWeight {
+    type Item = ();
+    fn id() {}
+    fn prod(_: &(), _: &()) {}
+}
+
+/// 和．前計算の途中の段では `u64` を超えうるので `2^64` で割った余りをとる
+///
+/// `prod(v, k)` の `k` 本の辺の和が `u64` に収まれば正しい値になる．
+pub enum AddU64 {}
+
+impl DoublingType for AddU64 {
+    type Item = u64;
+    fn id() -> u64 {
+        0
+    }
+    fn prod(a: &u64, b: &u64) -> u64 {
+        a.wrapping_add(*b)
+    }
+}
+
+const LOG: usize = 64;
+
+/// `next[j][v]`: `v` から `2^j` 回進んだ先，`prod[j][v]`: その間の辺の積
+pub struct Doubling<T: DoublingType> {
+    next: Vec<Vec<usize>>,
+    prod: Vec<Vec<T::Item>>,
+}
+
+impl<T: DoublingType> Doubling<T> {
+    /// `weight[v]` は辺 `v -> next[v]` の値
+    pub fn new(next: &[usize], weight: &[T::Item]) -> Self {
+        let n = next.len();
+        assert_eq!(n, weight.len());
+        assert!(next.iter().all(|&v| v < n), "index out");
+        let mut nexts = vec![next.to_vec()];
+        let mut prods = vec![weight.to_vec()];
+        for j in 1..LOG {
+            let (nx, pr) = (&nexts[j - 1], &prods[j - 1]);
+            let next = (0..n).map(|v| nx[nx[v]]).collect();
+            let prod = (0..n).map(|v| T::prod(&pr[v], &pr[nx[v]])).collect();
+            nexts.push(next);
+            prods.push(prod);
+        }
+        Self {
+            next: nexts,
+            prod: prods,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.next[0].len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn kth_successor(&self, mut v: usize, k: u64) -> usize {
+        assert!(v < self.len(), "index out: {}/{}", v, self.len());
+        for j in 0..LOG {
+            if k >> j & 1 == 1 {
+                v = self.next[j][v];
+            }
+        }
+        v
+    }
+    /// (`v` から `k` 回進んだ先, 通った `k` 本の辺の積)
+    pub fn prod(&self, mut v: usize, k: u64) -> (usize, T::Item) {
+        assert!(v < self.len(), "index out: {}/{}", v, self.len());
+        let mut acc = T::id();
+        for j in 0..LOG {
+            if k >> j & 1 == 1 {
+                acc = T::prod(&acc, &self.prod[j][v]);
+                v = self.next[j][v];
+            }
+        }
+        (v, acc)
+    }
+}
+
+impl Doubling<NoWeight> {
+    pub fn from_next(next: &[usize]) -> Self {
+        Self::new(next, &vec![(); next.len()])
+    }
+}
+
+/// `start` から進んでいったときの (閉路に入るまでの長さ, 閉路の長さ)
+pub fn rho(next: &[usize], start: usize) -> (usize, usize) {
+    let mut visited = vec![usize::MAX; next.len()];
+    let mut v = start;
+    let mut t = 0;
+    while visited[v] == usize::MAX {
+        visited[v] = t;
+        v = next[v];
+        t += 1;
+    }
+    (visited[v], t - visited[v])
+}
@@ -0,0 +1,253 @@
+use std::{
+    cell::Cell,
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+fn main() {
+    for m in [7, 1_000_000_007, 2_147_483_647] {
+        DynModInt::set_modulus(m);
+        let a = DynModInt::new(3);
+        let b = DynModInt::from(-1);
+        dbg!(DynModInt::modulus(), a + b, a * b, a / b, a.pow(10));
+        dbg!((1..=10).map(DynModInt::new).product::<DynModInt>());
+    }
+    let barrett = Barrett::new(998244353);
+    dbg!(barrett.mul(998244352, 998244352), barrett.reduce(1 << 40));
+}
+
+/// `m` で割った余りを，掛け算とシフトで求める
+#[derive(Clone, Copy, Debug)]
+pub struct Barrett {
+    m: u32,
+    // ceil(2^64 / m)
+    im: u64,
+}
+
+impl Barrett {
+    /// `1 <= m < 2^31`
+    pub const fn new(m: u32) -> Self {
+        assert!(1 <= m && m < 1 << 31);
+        Self {
+            m,
+            im: (u64::MAX / m as u64).wrapping_add(1),
+        }
+    }
+    pub const fn modulus(&self) -> u32 {
+        self.m
+    }
+    /// `z % m`．`z < m^2` でなければならない
+    pub fn reduce(&self, z: u64) -> u32 {
+        let x = ((z as u128 * self.im as u128) >> 64) as u64;
+        let mut v = z.wrapping_sub(x.wrapping_mul(self.m as u64)) as u32;
+        if self.m <= v {
+            v = v.wrapping_add(self.m);
+        }
+        v
+    }
+    /// `a * b % m`．`a, b < m` でなければならない
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        self.reduce(a as u64 * b as u64)
+    }
+}
+
+thread_local! {
+    static BARRETT: Cell<Barrett> = const { Cell::new(Barrett::new(998244353)) };
+}
+
+/// 法を実行時に決める整数．法はスレッドごとに1つで，初期値は `998244353`
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    val: u32,
+}
+
+impl DynModInt {
+    /// 法を変更する．それまでに作った値は意味を失う．
+    pub fn set_modulus(m: u32) {
+        BARRETT.with(|b| b.set(Barrett::new(m)));
+    }
+    fn barrett() -> Barrett {
+        BARRETT.with(|b| b.get())
+    }
+    pub fn modulus() -> u32 {
+        Self::barrett().modulus()
+    }
+    pub fn new(val: u32) -> Self {
+        Self {
+            val: val % Self::modulus(),
+        }
+    }
+    /// `val < modulus()` でなければならない
+    pub const fn raw(val: u32) -> Self {
+        Self { val }
+    }
+    pub const fn val(self) -> u32 {
+        self.val
+    }
+    pub fn pow(self, mut exp: u64) -> Self {
+        let barrett = Self::barrett();
+        let mut res = 1 % barrett.modulus();
+        let mut base = self.val;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = barrett.mul(res, base);
+            }
+            base = barrett.mul(base, base);
+            exp >>= 1;
+        }
+        Self::raw(res)
+    }
+    /// 逆元．法と互いに素でなければ panic
+    pub fn inv(self) -> Self {
+        let m = Self::modulus();
+        let (mut a, mut b) = (self.val as i64, m as i64);
+        let (mut x, mut y) = (1, 0);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        assert_eq!(a, 1, "{} has no inverse modulo {}", self.val, m);
+        Self::raw(x.rem_euclid(m as i64) as u32)
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for DynModInt {
+            fn from(x: $t) -> Self {
+                Self::raw((x as u128 % Self::modulus() as u128) as u32)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for DynModInt {
+            fn from(x: $t) -> Self {
+                Self::raw((x as i128).rem_euclid(Self::modulus() as i128) as u32)
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize, u128);
+impl_from_signed!(i8, i16, i32, i64, isize, i128);
+
+impl fmt::Display for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.val.fmt(f)
+    }
+}
+
+impl fmt::Debug for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.val.fmt(f)
+    }
+}
+
+impl Neg for DynModInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::raw(if self.val == 0 {
+            0
+        } else {
+            Self::modulus() - self.val
+        })
+    }
+}
+
+impl AddAssign for DynModInt {
+    fn add_assign(&mut self, rhs: Self) {
+        let m = Self::modulus();
+        self.val += rhs.val;
+        if self.val >= m {
+            self.val -= m;
+        }
+    }
+}
+
+impl SubAssign for DynModInt {
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.val < rhs.val {
+            self.val += Self::modulus();
+        }
+        self.val -= rhs.val;
+    }
+}
+
+impl MulAssign for DynModInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.val = Self::barrett().mul(self.val, rhs.val);
+    }
+}
+
+impl DivAssign for DynModInt {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inv();
+    }
+}
+
+macro_rules! impl_binop {
+    ($($trait:ident, $method:ident, $assign:ident;)*) => {$(
+        impl $trait for DynModInt {
+            type Output = Self;
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assign(rhs);
+                self
+            }
+        }
+        impl $trait<&DynModInt> for DynModInt {
+            type Output = Self;
+            fn $method(self, rhs: &Self) -> Self {
+                self.$method(*rhs)
+            }
+        }
+        impl $trait<DynModInt> for &DynModInt {
+            type Output = DynModInt;
+            fn $method(self, rhs: DynModInt) -> DynModInt {
+                (*self).$method(rhs)
+            }
+        }
+        impl $trait<&DynModInt> for &DynModInt {
+            type Output = DynModInt;
+            fn $method(self, rhs: &DynModInt) -> DynModInt {
+                (*self).$method(*rhs)
+            }
+        }
+    )*};
+}
+
+impl_binop! {
+    Add, add, add_assign;
+    Sub, sub, sub_assign;
+    Mul, mul, mul_assign;
+    Div, div, div_assign;
+}
+
+impl Sum for DynModInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::raw(0), |a, b| a + b)
+    }
+}
+
+impl<'a> Sum<&'a Self> for DynModInt {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for DynModInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), |a, b| a * b)
+    }
+}
+
+impl<'a> Product<&'a Self> for DynModInt {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+
+fn main() {
+    let mut tree = Eertree::new();
+    for c in "abacaba".chars() {
+        dbg!(c, tree.push(c));
+    }
+    dbg!(tree.distinct_count());
+    for (v, occ) in tree.occurrences().into_iter().enumerate().skip(2) {
+        dbg!(tree.palindrome(v).iter().collect::<String>(), occ);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Node<T> {
+    /// 回文の長さ．ノード `0` は `-1`
+    pub len: isize,
+    /// 最長の真の回文接尾辞のノード
+    pub link: usize,
+    /// 両端に `c` をつけた回文のノード
+    pub next: BTreeMap<T, usize>,
+    /// このノードが最長回文接尾辞になった回数
+    pub count: usize,
+    /// この回文が初めて現れたときの終端の位置
+    pub end: usize,
+}
+
+/// ノード `0` は長さ `-1` の，ノード `1` は長さ `0` の回文 (根)
+pub struct Eertree<T> {
+    s: Vec<T>,
+    nodes: Vec<Node<T>>,
+    last: usize,
+}
+
+impl<T: Ord + Clone> Default for Eertree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> Eertree<T> {
+    pub fn new() -> Self {
+        let root = |len| Node {
+            len,
+            link: 0,
+            next: BTreeMap::new(),
+            count: 0,
+            end: 0,
+        };
+        Self {
+            s: vec![],
+            nodes: vec![root(-1), root(0)],
+            last: 1,
+        }
+    }
+    pub fn nodes(&self) -> &[Node<T>] {
+        &self.nodes
+    }
+    /// 今の文字列の最長回文接尾辞のノード
+    pub fn last(&self) -> usize {
+        self.last
+    }
+    pub fn as_slice(&self) -> &[T] {
+        &self.s
+    }
+    /// ノード `v` の回文接尾辞であって，その前の文字が末尾の文字と一致するような最長のもの
+    fn find(&self, mut v: usize) -> usize {
+        let i = self.s.len() - 1;
+        loop {
+            let len = self.nodes[v].len;
+            if len == -1 || len < i as isize && self.s[i - 1 - len as usize] == self.s[i] {
+                return v;
+            }
+            v = self.nodes[v].link;
+        }
+    }
+    /// `c` を追加する．新しい回文が現れたら `true`
+    pub fn push(&mut self, c: T) -> bool {
+        self.s.push(c.clone());
+        let v = self.find(self.last);
+        if let Some(&to) = self.nodes[v].next.get(&c) {
+            self.last = to;
+            self.nodes[to].count += 1;
+            return false;
+        }
+        let len = self.nodes[v].len + 2;
+        let link = if len == 1 {
+            1
+        } else {
+            let u = self.find(self.nodes[v].link);
+            self.nodes[u].next[&c]
+        };
+        let to = self.nodes.len();
+        self.nodes.push(Node {
+            len,
+            link,
+            next: BTreeMap::new(),
+            count: 1,
+            end: self.s.len(),
+        });
+        self.nodes[v].next.insert(c, to);
+        self.last = to;
+        true
+    }
+    /// 空でない相異なる回文の個数
+    pub fn distinct_count(&self) -> usize {
+        self.nodes.len() - 2
+    }
+    /// 各ノードの回文の出現回数
+    pub fn occurrences(&self) -> Vec<usize> {
+        let mut occ: Vec<usize> = self.nodes.iter().map(|node| node.count).collect();
+        for v in (2..self.nodes.len()).rev() {
+            occ[self.nodes[v].link] += occ[v];
+        }
+        occ
+    }
+    /// ノード `v` の回文
+    pub fn palindrome(&self, v: usize) -> &[T] {
+        let node = &self.nodes[v];
+        let len = node.len.max(0) as usize;
+        &self.s[node.end - len..node.end]
+    }
+}
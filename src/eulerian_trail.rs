@@ -0,0 +1,84 @@
+fn main() {
+    // 多重辺と自己ループを含む無向グラフ
+    let edges = [(0, 1), (1, 2), (2, 0), (0, 1), (1, 1), (1, 3)];
+    dbg!(eulerian_trail(4, &edges, false));
+    // 有向グラフ
+    let edges = [(0, 1), (1, 2), (2, 0), (0, 0), (2, 3)];
+    dbg!(eulerian_trail(4, &edges, true));
+    // 存在しない
+    dbg!(eulerian_trail(4, &[(0, 1), (2, 3)], false));
+}
+
+/// `(頂点の列, 辺の番号の列)`．閉路が存在するなら閉路を返す．
+pub fn eulerian_trail(
+    n: usize,
+    edges: &[(usize, usize)],
+    directed: bool,
+) -> Option<(Vec<usize>, Vec<usize>)> {
+    let m = edges.len();
+    let mut adj = vec![vec![]; n];
+    let mut balance = vec![0i64; n];
+    for (i, &(u, v)) in edges.iter().enumerate() {
+        assert!(u < n && v < n, "index out: ({}, {})/{}", u, v, n);
+        adj[u].push((v, i));
+        balance[u] += 1;
+        if directed {
+            balance[v] -= 1;
+        } else {
+            adj[v].push((u, i));
+            balance[v] += 1;
+        }
+    }
+    if m == 0 {
+        return Some((if n == 0 { vec![] } else { vec![0] }, vec![]));
+    }
+    let start = if directed {
+        let mut start = None;
+        for (v, &b) in balance.iter().enumerate() {
+            match b {
+                0 => {}
+                1 if start.is_none() => start = Some(v),
+                -1 => {}
+                _ => return None,
+            }
+        }
+        if balance.iter().filter(|&&b| b == -1).count() != start.iter().count() {
+            return None;
+        }
+        start
+    } else {
+        let odd: Vec<_> = (0..n).filter(|&v| balance[v] % 2 == 1).collect();
+        match odd.len() {
+            0 => None,
+            2 => Some(odd[0]),
+            _ => return None,
+        }
+    }
+    .unwrap_or(edges[0].0);
+
+    let mut used = vec![false; m];
+    let mut iter = vec![0; n];
+    let mut vertices = Vec::with_capacity(m + 1);
+    let mut trail = Vec::with_capacity(m + 1);
+    let mut stack = vec![(start, usize::MAX)];
+    while let Some(&(v, e)) = stack.last() {
+        while iter[v] < adj[v].len() && used[adj[v][iter[v]].1] {
+            iter[v] += 1;
+        }
+        if let Some(&(to, id)) = adj[v].get(iter[v]) {
+            used[id] = true;
+            stack.push((to, id));
+        } else {
+            stack.pop();
+            vertices.push(v);
+            trail.push(e);
+        }
+    }
+    if vertices.len() != m + 1 {
+        return None;
+    }
+    vertices.reverse();
+    trail.pop();
+    trail.reverse();
+    Some((vertices, trail))
+}
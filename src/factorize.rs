@@ -0,0 +1,137 @@
+#[allow(dead_code)]
+#[path = "montgomery.rs"]
+mod montgomery;
+
+use montgomery::Montgomery64;
+
+fn main() {
+    dbg!(is_prime(998244353), is_prime(1_000_000_007 * 998244353));
+    dbg!(is_prime(18446744073709551557), is_prime(3215031751));
+    dbg!(factorize(1_000_000_007 * 998244353));
+    dbg!(factorize(u64::MAX), factorize(720720), factorize(1));
+    dbg!(factorize(4611686014132420609)); // (2^31 - 1)^2
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// 決定的 Miller–Rabin 素数判定
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    // n < 2^64 ならこの 7 つで十分
+    const WITNESSES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+    let mont = Montgomery64::new(n);
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let one = mont.to_mont(1);
+    let minus_one = mont.to_mont(n - 1);
+    WITNESSES.iter().all(|&a| {
+        let a = a % n;
+        if a == 0 {
+            return true;
+        }
+        let mut x = mont.pow(mont.to_mont(a), d);
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = mont.mul(x, x);
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// 合成数 `n` (奇数) の非自明な約数をひとつ，Brent による Pollard の rho 法で求める
+fn find_factor(n: u64) -> u64 {
+    let mont = Montgomery64::new(n);
+    // x ← x^2 + c
+    for c in 1.. {
+        let c = mont.to_mont(c % n);
+        let f = |x: u64| mont.add(mont.mul(x, x), c);
+        // 差の積をまとめて gcd をとる
+        const BATCH: u64 = 128;
+        let mut y = mont.to_mont(2);
+        let mut r = 1;
+        let mut q = mont.to_mont(1);
+        let (mut x, mut ys);
+        let mut g;
+        loop {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            loop {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mont.mul(q, x.abs_diff(y));
+                }
+                g = gcd(mont.from_mont(q), n);
+                k += BATCH;
+                if k >= r || g != 1 {
+                    break;
+                }
+            }
+            r *= 2;
+            if g != 1 {
+                break;
+            }
+        }
+        if g == n {
+            // まとめすぎて n になったら1つずつやり直す
+            loop {
+                ys = f(ys);
+                g = gcd(mont.from_mont(x.abs_diff(ys)), n);
+                if g != 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// 素因数を重複込みで昇順に．`n >= 1`
+pub fn factorize(mut n: u64) -> Vec<u64> {
+    assert_ne!(n, 0);
+    let mut res = vec![];
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while n.is_multiple_of(p) {
+            res.push(p);
+            n /= p;
+        }
+    }
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            res.push(m);
+            continue;
+        }
+        let d = find_factor(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+    res.sort_unstable();
+    res
+}
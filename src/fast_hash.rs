@@ -0,0 +1,129 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::OnceLock;
+
+fn main() {
+    let mut cnt = FastHashMap::default();
+    for s in ["apple", "banana", "apple", "cherry", "banana", "apple"] {
+        *cnt.entry(s).or_insert(0) += 1;
+    }
+    let mut cnt: Vec<_> = cnt.into_iter().collect();
+    cnt.sort();
+    dbg!(cnt);
+
+    // 下位ビットがすべて同じ鍵でも偏らない
+    let set: FastHashSet<u64> = (0..200000u64).map(|i| i << 32).collect();
+    dbg!(
+        set.len(),
+        set.contains(&(12345 << 32)),
+        set.contains(&12345)
+    );
+
+    // 同じ実行の中では seed は同じ
+    let (a, b) = (FastBuildHasher::default(), FastBuildHasher::default());
+    dbg!(a.hash_one((1, 2)) == b.hash_one((1, 2)));
+}
+
+/// 実行ごとにランダムな seed をもつ速い hasher の `HashMap`
+///
+/// `HashMap::new()` は使えないので，`FastHashMap::default()` で作る．
+pub type FastHashMap<K, V> = HashMap<K, V, FastBuildHasher>;
+/// 実行ごとにランダムな seed をもつ速い hasher の `HashSet`
+pub type FastHashSet<T> = HashSet<T, FastBuildHasher>;
+
+/// 実行ごとに 1 回だけ決める seed．時刻とスタックのアドレスを splitmix64 で混ぜる
+fn seed() -> u64 {
+    static SEED: OnceLock<u64> = OnceLock::new();
+    *SEED.get_or_init(|| {
+        let t = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+        let local = 0u8;
+        let addr = &local as *const u8 as u64;
+        let mut z = (t ^ addr.rotate_left(32)).wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FastBuildHasher {
+    seed: u64,
+}
+
+impl Default for FastBuildHasher {
+    fn default() -> Self {
+        Self { seed: seed() }
+    }
+}
+
+impl BuildHasher for FastBuildHasher {
+    type Hasher = FastHasher;
+    fn build_hasher(&self) -> FastHasher {
+        FastHasher { hash: self.seed }
+    }
+}
+
+/// FxHash と同じく 1 語ごとに `rotate`, `xor`, `mul` をし，`finish` で全体を混ぜる
+#[derive(Clone, Copy, Debug)]
+pub struct FastHasher {
+    hash: u64,
+}
+
+const K: u64 = 0xf1357aea2e62a9c5;
+
+impl FastHasher {
+    #[inline]
+    fn add(&mut self, x: u64) {
+        self.hash = (self.hash.rotate_left(26) ^ x).wrapping_mul(K);
+    }
+}
+
+impl Hasher for FastHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for c in &mut chunks {
+            self.add(u64::from_le_bytes(c.try_into().unwrap()));
+        }
+        let rest = chunks.remainder();
+        if !rest.is_empty() {
+            let mut buf = [0; 8];
+            buf[..rest.len()].copy_from_slice(rest);
+            self.add(u64::from_le_bytes(buf));
+        }
+    }
+    #[inline]
+    fn write_u8(&mut self, x: u8) {
+        self.add(x as u64);
+    }
+    #[inline]
+    fn write_u16(&mut self, x: u16) {
+        self.add(x as u64);
+    }
+    #[inline]
+    fn write_u32(&mut self, x: u32) {
+        self.add(x as u64);
+    }
+    #[inline]
+    fn write_u64(&mut self, x: u64) {
+        self.add(x);
+    }
+    #[inline]
+    fn write_u128(&mut self, x: u128) {
+        self.add(x as u64);
+        self.add((x >> 64) as u64);
+    }
+    #[inline]
+    fn write_usize(&mut self, x: usize) {
+        self.add(x as u64);
+    }
+    /// 掛け算だけでは上位ビットの影響が下位ビットに伝わらないので，最後に混ぜる
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut z = self.hash;
+        z = (z ^ (z >> 32)).wrapping_mul(0xd6e8feb86659fd93);
+        z ^ (z >> 32)
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    f64::consts::PI,
+    ops::{Add, Mul, Sub},
+};
+
+fn main() {
+    dbg!(convolution_f64(&[1.0, 2.0, 3.0], &[0.5, 0.25]));
+    dbg!(convolution_round(&[1, 2, 3, 4], &[5, 6, 7, 8, 9]));
+
+    let mut a: Vec<Complex> = (0..8).map(|i| Complex::new(i as f64, 0.0)).collect();
+    fft(&mut a, false);
+    fft(&mut a, true);
+    dbg!(a.iter().map(|z| z.re).collect::<Vec<_>>());
+
+    let n = 500_000;
+    let a: Vec<i64> = (0..n).map(|i| i % 1000).collect();
+    let c = convolution_round(&a, &a);
+    let naive: i64 = (0..n)
+        .map(|i| a[i as usize] * a[(n - 1 - i) as usize])
+        .sum();
+    dbg!(c[n as usize - 1], naive);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+    /// `e^{iθ}`
+    pub fn polar(theta: f64) -> Self {
+        Self::new(theta.cos(), theta.sin())
+    }
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+/// 長さが2冪の `a` の離散 Fourier 変換．`inv` なら逆変換 (`1 / n` 倍まで含む)
+pub fn fft(a: &mut [Complex], inv: bool) {
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    // ビット反転の順に並べ替える
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let sign = if inv { -1.0 } else { 1.0 };
+    // roots[j] = e^{±2πij / n}．毎回掛けていくと誤差がたまるので，直接 cos, sin で求める
+    let roots: Vec<Complex> = (0..n / 2)
+        .map(|j| Complex::polar(sign * 2.0 * PI * j as f64 / n as f64))
+        .collect();
+    let mut len = 1;
+    while len < n {
+        let step = n / (2 * len);
+        for block in a.chunks_exact_mut(2 * len) {
+            let (l, r) = block.split_at_mut(len);
+            for (j, (x, y)) in l.iter_mut().zip(r).enumerate() {
+                let (u, v) = (*x, *y * roots[j * step]);
+                *x = u + v;
+                *y = u - v;
+            }
+        }
+        len *= 2;
+    }
+    if inv {
+        let n_inv = 1.0 / n as f64;
+        a.iter_mut().for_each(|z| *z = *z * n_inv);
+    }
+}
+
+/// 実数列の畳み込み．FFT を正変換と逆変換の1回ずつで済ませる
+pub fn convolution_f64(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    // z = a + ib
+    let mut z = vec![Complex::default(); n];
+    for (i, &x) in a.iter().enumerate() {
+        z[i].re = x;
+    }
+    for (i, &x) in b.iter().enumerate() {
+        z[i].im = x;
+    }
+    fft(&mut z, false);
+    // A[k] = (Z[k] + conj(Z[-k])) / 2, B[k] = (Z[k] - conj(Z[-k])) / 2i より
+    // A[k] B[k] = (Z[k]^2 - conj(Z[-k])^2) / 4i
+    let mut c: Vec<Complex> = (0..n)
+        .map(|k| {
+            let (x, y) = (z[k], z[(n - k) & (n - 1)].conj());
+            let d = x * x - y * y;
+            Complex::new(d.im, -d.re) * 0.25
+        })
+        .collect();
+    fft(&mut c, true);
+    c.into_iter().take(len).map(|z| z.re).collect()
+}
+
+/// 整数列の畳み込みを，`convolution_f64` の結果を丸めて求める．結果が大きいと誤差で正しく丸められない
+pub fn convolution_round(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let a: Vec<f64> = a.iter().map(|&x| x as f64).collect();
+    let b: Vec<f64> = b.iter().map(|&x| x as f64).collect();
+    convolution_f64(&a, &b)
+        .into_iter()
+        .map(|x| x.round() as i64)
+        .collect()
+}
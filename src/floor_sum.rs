@@ -0,0 +1,56 @@
+fn main() {
+    dbg!(floor_sum(4, 10, 6, 3), floor_sum_naive(4, 10, 6, 3));
+    dbg!(floor_sum(6, 5, -4, 3), floor_sum_naive(6, 5, -4, 3));
+    dbg!(floor_sum(
+        1_000_000_000,
+        1_000_000_000,
+        999_999_999,
+        -1_000_000_000
+    ));
+    // 直線 y = (2x + 1) / 3 より下の，0 <= x < 10 の格子点 (y >= 1) の個数
+    dbg!(floor_sum(10, 3, 2, 1));
+}
+
+/// `Σ_{i=0}^{n-1} floor((a i + b) / m)`．`n >= 0`, `m >= 1`．結果は `i64` に収まる必要がある
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    assert!(n >= 0 && m >= 1);
+    let (n, m, mut a, mut b) = (n as i128, m as i128, a as i128, b as i128);
+    let mut res = 0;
+    // a, b を 0 以上 m 未満にする
+    if !(0..m).contains(&a) {
+        res += n * (n - 1) / 2 * a.div_euclid(m);
+        a = a.rem_euclid(m);
+    }
+    if !(0..m).contains(&b) {
+        res += n * b.div_euclid(m);
+        b = b.rem_euclid(m);
+    }
+    (res + floor_sum_unsigned(n as u128, m as u128, a as u128, b as u128) as i128) as i64
+}
+
+fn floor_sum_unsigned(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+    let mut res = 0;
+    loop {
+        if a >= m {
+            res += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            res += n * (b / m);
+            b %= m;
+        }
+        // 直線 y = (ax + b) / m の下の格子点を，x と y を入れ替えて数える
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+    res
+}
+
+pub fn floor_sum_naive(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    (0..n).map(|i| (a * i + b).div_euclid(m)).sum()
+}
@@ -0,0 +1,300 @@
+#[allow(dead_code)]
+#[path = "convolution.rs"]
+pub mod convolution;
+#[allow(dead_code)]
+#[path = "sqrt_mod.rs"]
+pub mod sqrt_mod;
+
+use convolution::{convolution, modint::ModInt};
+use sqrt_mod::sqrt_mod;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+fn main() {
+    type F = Fps<998244353>;
+    let f = F::from_u32(&[1, 1]);
+    dbg!(f.inv(5), f.log(5), F::from_u32(&[0, 1]).exp(6));
+    dbg!(f.pow(3, 6), F::from_u32(&[0, 0, 4, 4, 1]).sqrt(5));
+    dbg!(F::from_u32(&[3]).sqrt(3)); // 3 は平方非剰余
+    dbg!(F::from_u32(&[1, 2, 3]).taylor_shift(1.into())); // 1 + 2(x + 1) + 3(x + 1)^2
+    let (q, r) = F::from_u32(&[5, 4, 3, 2, 1]).div_rem(&F::from_u32(&[1, 1]));
+    dbg!(&q, &r, &(&q * &F::from_u32(&[1, 1])) + &r);
+    dbg!(F::from_u32(&[1, 2, 3]).eval(10.into()));
+
+    // 分割数
+    let n = 10;
+    let mut euler = F::new(vec![0.into(); n]);
+    for k in 1..n {
+        let mut j = k;
+        while j < n {
+            euler.0[j] += ModInt::new(1) / ModInt::from(j / k);
+            j += k;
+        }
+    }
+    dbg!(euler.exp(n));
+}
+
+/// 形式的冪級数．`0[i]` が `x^i` の係数
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fps<const M: u32>(pub Vec<ModInt<M>>);
+
+impl<const M: u32> Fps<M> {
+    pub fn new(a: Vec<ModInt<M>>) -> Self {
+        Self(a)
+    }
+    pub fn from_u32(a: &[u32]) -> Self {
+        Self(a.iter().map(|&x| ModInt::new(x)).collect())
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// `x^i` の係数 (`i` が長さ以上なら `0`)
+    pub fn coef(&self, i: usize) -> ModInt<M> {
+        self.0.get(i).copied().unwrap_or_default()
+    }
+    /// `mod x^n`．足りなければ `0` で埋める
+    pub fn prefix(&self, n: usize) -> Self {
+        let mut a = self.0[..n.min(self.len())].to_vec();
+        a.resize(n, ModInt::raw(0));
+        Self(a)
+    }
+    /// 末尾の `0` を取り除く
+    pub fn shrink(&mut self) {
+        while self.0.last() == Some(&ModInt::raw(0)) {
+            self.0.pop();
+        }
+    }
+    pub fn eval(&self, x: ModInt<M>) -> ModInt<M> {
+        self.0
+            .iter()
+            .rev()
+            .fold(ModInt::raw(0), |acc, &c| acc * x + c)
+    }
+    pub fn derivative(&self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * ModInt::from(i))
+                .collect(),
+        )
+    }
+    /// 定数項は `0`
+    pub fn integral(&self) -> Self {
+        let n = self.len();
+        let inv = inverses::<M>(n);
+        let mut a = vec![ModInt::raw(0); n + 1];
+        for i in 0..n {
+            a[i + 1] = self.0[i] * inv[i + 1];
+        }
+        Self(a)
+    }
+    /// `1 / f mod x^n`．定数項は `0` でない
+    pub fn inv(&self, n: usize) -> Self {
+        assert_ne!(
+            self.coef(0),
+            ModInt::raw(0),
+            "constant term must be nonzero"
+        );
+        // g ← g (2 - f g)
+        let mut g = Self(vec![self.0[0].inv()]);
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            let mut h = (&self.prefix(k) * &g).prefix(k);
+            h.0.iter_mut().for_each(|c| *c = -*c);
+            h.0[0] += ModInt::new(2);
+            g = (&g * &h).prefix(k);
+        }
+        g.prefix(n)
+    }
+    /// `log f mod x^n`．定数項は `1`
+    pub fn log(&self, n: usize) -> Self {
+        assert_eq!(self.coef(0), ModInt::new(1), "constant term must be 1");
+        if n == 0 {
+            return Self(vec![]);
+        }
+        (&self.prefix(n).derivative() * &self.inv(n))
+            .prefix(n - 1)
+            .integral()
+    }
+    /// `exp f mod x^n`．定数項は `0`
+    pub fn exp(&self, n: usize) -> Self {
+        assert_eq!(self.coef(0), ModInt::raw(0), "constant term must be 0");
+        // g ← g (1 - log g + f)
+        let mut g = Self(vec![ModInt::new(1)]);
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            let mut h = &self.prefix(k) - &g.log(k);
+            h.0[0] += ModInt::new(1);
+            g = (&g * &h).prefix(k);
+        }
+        g.prefix(n)
+    }
+    /// `f^k mod x^n`
+    pub fn pow(&self, k: u64, n: usize) -> Self {
+        if k == 0 {
+            return Self::from_u32(&[1]).prefix(n);
+        }
+        let f = self.prefix(n);
+        let Some(d) = f.0.iter().position(|&c| c != ModInt::raw(0)) else {
+            return Self(vec![ModInt::raw(0); n]);
+        };
+        if d as u128 * k as u128 >= n as u128 {
+            return Self(vec![ModInt::raw(0); n]);
+        }
+        let shift = d * k as usize;
+        // f = c x^d (1 + ...) とすると f^k = c^k x^{dk} exp(k log(1 + ...))
+        let c = f.0[d];
+        let c_inv = c.inv();
+        let g = Self(f.0[d..].iter().map(|&x| x * c_inv).collect());
+        let mut g = g.log(n - shift);
+        let k_mod = ModInt::from(k);
+        g.0.iter_mut().for_each(|x| *x *= k_mod);
+        let ck = c.pow(k);
+        let mut a = vec![ModInt::raw(0); shift];
+        a.extend(g.exp(n - shift).0.into_iter().map(|x| x * ck));
+        Self(a)
+    }
+    /// `g^2 = f mod x^n` となる `g` のひとつ．なければ `None`
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        let f = self.prefix(n);
+        let Some(d) = f.0.iter().position(|&c| c != ModInt::raw(0)) else {
+            return Some(f);
+        };
+        if d % 2 == 1 {
+            return None;
+        }
+        let c = f.0[d];
+        let s = ModInt::raw(sqrt_mod(c.val() as u64, M as u64)? as u32);
+        let c_inv = c.inv();
+        let f = Self(f.0[d..].iter().map(|&x| x * c_inv).collect());
+        // 定数項が 1 のものについて g ← (g + f / g) / 2
+        let m = n - d / 2;
+        let inv2 = ModInt::<M>::new(2).inv();
+        let mut g = Self(vec![ModInt::new(1)]);
+        let mut k = 1;
+        while k < m {
+            k *= 2;
+            let h = (&f.prefix(k) * &g.inv(k)).prefix(k);
+            g = Self(
+                (&g.prefix(k) + &h)
+                    .0
+                    .into_iter()
+                    .map(|x| x * inv2)
+                    .collect(),
+            );
+        }
+        let mut a = vec![ModInt::raw(0); d / 2];
+        a.extend(g.prefix(m).0.into_iter().map(|x| x * s));
+        Some(Self(a))
+    }
+    /// `f(x + c)`
+    pub fn taylor_shift(&self, c: ModInt<M>) -> Self {
+        let n = self.len();
+        if n == 0 {
+            return Self(vec![]);
+        }
+        let mut fact = vec![ModInt::new(1); n];
+        for i in 1..n {
+            fact[i] = fact[i - 1] * ModInt::from(i);
+        }
+        let mut fact_inv = vec![fact[n - 1].inv(); n];
+        for i in (1..n).rev() {
+            fact_inv[i - 1] = fact_inv[i] * ModInt::from(i);
+        }
+        // g[i] i! = Σ_j (f[j] j!) (c^{j - i} / (j - i)!)
+        let a: Vec<ModInt<M>> = (0..n).rev().map(|j| self.0[j] * fact[j]).collect();
+        let mut b = vec![ModInt::raw(0); n];
+        let mut pw = ModInt::new(1);
+        for k in 0..n {
+            b[k] = pw * fact_inv[k];
+            pw *= c;
+        }
+        let ab = convolution(&a, &b);
+        Self((0..n).map(|i| ab[n - 1 - i] * fact_inv[i]).collect())
+    }
+    /// 多項式としての商と余り．どちらも末尾の `0` は取り除く
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let mut f = self.clone();
+        f.shrink();
+        let mut g = rhs.clone();
+        g.shrink();
+        assert!(!g.is_empty(), "division by zero polynomial");
+        if f.len() < g.len() {
+            return (Self(vec![]), f);
+        }
+        let k = f.len() - g.len() + 1;
+        let rev = |a: &Self| Self(a.0.iter().rev().copied().collect());
+        let q = (&rev(&f).prefix(k) * &rev(&g).inv(k)).prefix(k);
+        let mut q = rev(&q);
+        let mut r = (&f - &(&q * &g)).prefix(g.len() - 1);
+        q.shrink();
+        r.shrink();
+        (q, r)
+    }
+}
+
+/// `inv[i] = 1 / i` (`1 <= i <= n`)
+fn inverses<const M: u32>(n: usize) -> Vec<ModInt<M>> {
+    let mut inv = vec![ModInt::raw(0); n + 1];
+    if n >= 1 {
+        inv[1] = ModInt::new(1);
+    }
+    for i in 2..=n {
+        inv[i] = -inv[M as usize % i] * ModInt::from(M as usize / i);
+    }
+    inv
+}
+
+impl<const M: u32> Neg for &Fps<M> {
+    type Output = Fps<M>;
+    fn neg(self) -> Fps<M> {
+        Fps(self.0.iter().map(|&x| -x).collect())
+    }
+}
+
+impl<const M: u32> Add for &Fps<M> {
+    type Output = Fps<M>;
+    fn add(self, rhs: Self) -> Fps<M> {
+        let mut a = self.prefix(self.len().max(rhs.len()));
+        a.0.iter_mut().zip(&rhs.0).for_each(|(x, y)| *x += *y);
+        a
+    }
+}
+
+impl<const M: u32> Sub for &Fps<M> {
+    type Output = Fps<M>;
+    fn sub(self, rhs: Self) -> Fps<M> {
+        let mut a = self.prefix(self.len().max(rhs.len()));
+        a.0.iter_mut().zip(&rhs.0).for_each(|(x, y)| *x -= *y);
+        a
+    }
+}
+
+impl<const M: u32> Mul for &Fps<M> {
+    type Output = Fps<M>;
+    fn mul(self, rhs: Self) -> Fps<M> {
+        Fps(convolution(&self.0, &rhs.0))
+    }
+}
+
+/// 多項式としての商
+impl<const M: u32> Div for &Fps<M> {
+    type Output = Fps<M>;
+    fn div(self, rhs: Self) -> Fps<M> {
+        self.div_rem(rhs).0
+    }
+}
+
+/// 多項式としての余り
+impl<const M: u32> Rem for &Fps<M> {
+    type Output = Fps<M>;
+    fn rem(self, rhs: Self) -> Fps<M> {
+        self.div_rem(rhs).1
+    }
+}
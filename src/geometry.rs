@@ -0,0 +1,237 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+fn main() {
+    let a = Point::new(1, 2);
+    let b = Point::new(3, -1);
+    dbg!(a + b, a - b, a * 3, -a, a.dot(b), a.cross(b), a.norm2());
+    dbg!(ccw(Point::new(0, 0), Point::new(2, 0), Point::new(1, 1)));
+    dbg!(ccw(Point::new(0, 0), Point::new(2, 0), Point::new(3, 0)));
+    dbg!(ccw(Point::new(0, 0), Point::new(2, 0), Point::new(1, 0)));
+
+    let mut ps = vec![
+        Point::new(-1, -1),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(-1, 0),
+        Point::new(2, 2),
+        Point::new(0, -1),
+        Point::new(1, -1),
+    ];
+    sort_by_arg(&mut ps);
+    dbg!(&ps);
+
+    let p = Point::new(2.0, 3.0);
+    let (s, t) = (Point::new(0.0, 0.0), Point::new(4.0, 0.0));
+    dbg!(projection(p, s, t), reflection(p, s, t));
+    dbg!(
+        p.abs(),
+        p.arg(),
+        p.rotate(std::f64::consts::FRAC_PI_2),
+        p.unit()
+    );
+    dbg!(a.to_f64().abs());
+}
+
+/// 座標の型．`f64` は誤差 `EPS` 以下を `0` とみなす
+pub trait Coord:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    /// `-1`, `0`, `1`
+    fn sign(self) -> i32;
+    fn to_f64(self) -> f64;
+}
+
+impl Coord for i64 {
+    fn zero() -> Self {
+        0
+    }
+    fn sign(self) -> i32 {
+        self.signum() as i32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+pub const EPS: f64 = 1e-9;
+
+impl Coord for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+    fn sign(self) -> i32 {
+        if self > EPS {
+            1
+        } else if self < -EPS {
+            -1
+        } else {
+            0
+        }
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// 位置ベクトルと区別しない
+pub type Vector<T> = Point<T>;
+
+impl<T: Coord> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+    pub fn dot(self, rhs: Self) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+    /// `rhs` が `self` から反時計回りの側にあれば正
+    pub fn cross(self, rhs: Self) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+    /// 長さの 2 乗
+    pub fn norm2(self) -> T {
+        self.dot(self)
+    }
+    pub fn to_f64(self) -> Point<f64> {
+        Point::new(self.x.to_f64(), self.y.to_f64())
+    }
+    /// 偏角の比較．偏角は `[0, 2π)` で，原点はどれよりも小さいとする
+    pub fn cmp_arg(self, other: Self) -> Ordering {
+        // 原点なら 0，偏角が [0, π) なら 1，[π, 2π) なら 2
+        let half = |p: Self| match (p.x.sign(), p.y.sign()) {
+            (0, 0) => 0,
+            (x, y) if y > 0 || y == 0 && x > 0 => 1,
+            _ => 2,
+        };
+        half(self)
+            .cmp(&half(other))
+            .then_with(|| 0.cmp(&self.cross(other).sign()))
+    }
+}
+
+impl Point<f64> {
+    pub fn abs(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+    /// 偏角 (`(-π, π]`)
+    pub fn arg(self) -> f64 {
+        self.y.atan2(self.x)
+    }
+    /// 極座標から
+    pub fn polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+    /// 反時計回りに `theta` 回転
+    pub fn rotate(self, theta: f64) -> Self {
+        let (s, c) = theta.sin_cos();
+        Self::new(self.x * c - self.y * s, self.x * s + self.y * c)
+    }
+    /// 同じ向きの単位ベクトル
+    pub fn unit(self) -> Self {
+        self * (1.0 / self.abs())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({:?}, {:?})", self.x, self.y)
+    }
+}
+
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coord> Neg for Point<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// スカラー倍
+impl<T: Coord> Mul<T> for Point<T> {
+    type Output = Self;
+    fn mul(self, k: T) -> Self {
+        Self::new(self.x * k, self.y * k)
+    }
+}
+
+impl<T: Coord> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// `a` から `b` へ進んだときの `c` の位置
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ccw {
+    /// 左側
+    CounterClockwise,
+    /// 右側
+    Clockwise,
+    /// 直線 `ab` 上で，`a` より後ろ
+    OnlineBack,
+    /// 直線 `ab` 上で，`b` より先
+    OnlineFront,
+    /// 線分 `ab` 上 (端点を含む)
+    OnSegment,
+}
+
+pub fn ccw<T: Coord>(a: Point<T>, b: Point<T>, c: Point<T>) -> Ccw {
+    let (b, c) = (b - a, c - a);
+    match b.cross(c).sign() {
+        1 => Ccw::CounterClockwise,
+        -1 => Ccw::Clockwise,
+        _ if b.dot(c).sign() < 0 => Ccw::OnlineBack,
+        _ if (b.norm2() - c.norm2()).sign() < 0 => Ccw::OnlineFront,
+        _ => Ccw::OnSegment,
+    }
+}
+
+/// 偏角の昇順 (`cmp_arg`) に安定ソート
+pub fn sort_by_arg<T: Coord>(ps: &mut [Point<T>]) {
+    ps.sort_by(|&a, &b| a.cmp_arg(b));
+}
+
+/// `p` から直線 `ab` に下ろした垂線の足．`a != b`
+pub fn projection(p: Point<f64>, a: Point<f64>, b: Point<f64>) -> Point<f64> {
+    let v = b - a;
+    a + v * ((p - a).dot(v) / v.norm2())
+}
+
+/// 直線 `ab` について `p` と対称な点．`a != b`
+pub fn reflection(p: Point<f64>, a: Point<f64>, b: Point<f64>) -> Point<f64> {
+    projection(p, a, b) * 2.0 - p
+}
@@ -0,0 +1,63 @@
+fn main() {
+    // 0, 1, 2 と 3, 4, 5 がそれぞれ強く結ばれ，その間は弱い
+    let mut matrix = vec![vec![0; 6]; 6];
+    for &(u, v, w) in &[
+        (0, 1, 5),
+        (1, 2, 4),
+        (2, 0, 3),
+        (3, 4, 5),
+        (4, 5, 4),
+        (5, 3, 3),
+        (2, 3, 1),
+        (0, 5, 2),
+    ] {
+        matrix[u][v] += w;
+        matrix[v][u] += w;
+    }
+    dbg!(global_min_cut(&matrix));
+}
+
+/// (最小カットの値, カットの片側の頂点集合)
+pub fn global_min_cut(matrix: &[Vec<i64>]) -> (i64, Vec<usize>) {
+    let n = matrix.len();
+    assert!(n >= 2, "at least 2 vertices are required");
+    let mut w = matrix.to_vec();
+    // group[v]: v に併合された元の頂点たち
+    let mut group: Vec<Vec<usize>> = (0..n).map(|v| vec![v]).collect();
+    let mut alive: Vec<usize> = (0..n).collect();
+    let mut best = (i64::MAX, vec![]);
+    while alive.len() > 1 {
+        // maximum adjacency ordering
+        let mut conn = vec![0; n];
+        let mut added = vec![false; n];
+        let mut prev = alive[0];
+        let mut last = alive[0];
+        for _ in 0..alive.len() {
+            let v = *alive
+                .iter()
+                .filter(|&&v| !added[v])
+                .max_by_key(|&&v| conn[v])
+                .unwrap();
+            added[v] = true;
+            prev = last;
+            last = v;
+            for &u in &alive {
+                conn[u] += w[v][u];
+            }
+        }
+        let cut = conn[last] - w[last][last];
+        if cut < best.0 {
+            best = (cut, group[last].clone());
+        }
+        // last を prev に併合する
+        let moved = std::mem::take(&mut group[last]);
+        group[prev].extend(moved);
+        for &u in &alive {
+            w[prev][u] += w[last][u];
+            w[u][prev] = w[prev][u];
+        }
+        alive.retain(|&v| v != last);
+    }
+    best.1.sort_unstable();
+    best
+}
@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+use std::ops::{Index, IndexMut};
+
+fn main() {
+    let grid = Grid::from_bytes(&["S..#", ".#..", "...G"]);
+    let s = grid.find(&b'S').unwrap();
+    let g = grid.find(&b'G').unwrap();
+    dbg!(grid.h(), grid.w(), s, g, grid[(1, 1)] as char);
+    dbg!(grid.neighbors4((0, 0)).collect::<Vec<_>>());
+    dbg!(grid.neighbors8((1, 3)).collect::<Vec<_>>());
+    let dist = grid.bfs(&[s], |&c| c != b'#');
+    dbg!(dist[g], dist[(0, 3)]);
+    let counts = grid.map(|&c| (c == b'.') as u32);
+    dbg!(counts.positions().map(|p| counts[p]).sum::<u32>());
+}
+
+/// 上下左右
+pub const DIR4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// 周囲 8 方向
+pub const DIR8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// `h × w` の 2 次元配列．`(行, 列)` で添字づける
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    h: usize,
+    w: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(h: usize, w: usize, init: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(h, w, vec![init; h * w])
+    }
+    /// 行優先で並べた `data` から
+    pub fn from_vec(h: usize, w: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), h * w);
+        Self { h, w, data }
+    }
+    pub fn h(&self) -> usize {
+        self.h
+    }
+    pub fn w(&self) -> usize {
+        self.w
+    }
+    /// 範囲外なら `None`
+    pub fn get(&self, (i, j): (usize, usize)) -> Option<&T> {
+        (i < self.h && j < self.w).then(|| &self.data[i * self.w + j])
+    }
+    pub fn get_mut(&mut self, (i, j): (usize, usize)) -> Option<&mut T> {
+        (i < self.h && j < self.w).then(|| &mut self.data[i * self.w + j])
+    }
+    /// `i` 行目
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.w..(i + 1) * self.w]
+    }
+    /// すべての位置を行優先で
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let w = self.w;
+        (0..self.h).flat_map(move |i| (0..w).map(move |j| (i, j)))
+    }
+    /// 値が `x` である最初の位置 (行優先)
+    pub fn find(&self, x: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        let k = self.data.iter().position(|y| y == x)?;
+        Some((k / self.w, k % self.w))
+    }
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.h, self.w, self.data.iter().map(f).collect())
+    }
+    /// `p` から `dirs` の各方向に 1 つ進んだ位置のうち，範囲内のもの
+    pub fn neighbors<'a>(
+        &self,
+        (i, j): (usize, usize),
+        dirs: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (h, w) = (self.h, self.w);
+        dirs.iter().filter_map(move |&(di, dj)| {
+            let ni = i.checked_add_signed(di).filter(|&ni| ni < h)?;
+            let nj = j.checked_add_signed(dj).filter(|&nj| nj < w)?;
+            Some((ni, nj))
+        })
+    }
+    /// 上下左右で範囲内のもの
+    pub fn neighbors4(&self, p: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(p, &DIR4)
+    }
+    /// 周囲 8 方向で範囲内のもの
+    pub fn neighbors8(&self, p: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(p, &DIR8)
+    }
+    /// `starts` からの上下左右の移動での最短距離．`passable` を満たすマスだけを通る．届かなければ `None`
+    ///
+    /// 始点は `passable` を満たさなくてもよい．
+    pub fn bfs(
+        &self,
+        starts: &[(usize, usize)],
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut dist = Grid::new(self.h, self.w, None);
+        let mut queue = VecDeque::new();
+        for &s in starts {
+            if dist[s].is_none() {
+                dist[s] = Some(0);
+                queue.push_back(s);
+            }
+        }
+        while let Some(p) = queue.pop_front() {
+            let d = dist[p].unwrap();
+            for q in self.neighbors4(p) {
+                if dist[q].is_none() && passable(&self[q]) {
+                    dist[q] = Some(d + 1);
+                    queue.push_back(q);
+                }
+            }
+        }
+        dist
+    }
+}
+
+impl Grid<u8> {
+    /// 各行をバイト列で．行の長さはそろっていること
+    pub fn from_bytes<R: AsRef<[u8]>>(rows: &[R]) -> Self {
+        let h = rows.len();
+        let w = rows.first().map_or(0, |r| r.as_ref().len());
+        let mut data = Vec::with_capacity(h * w);
+        for r in rows {
+            assert_eq!(r.as_ref().len(), w, "rows must have the same length");
+            data.extend_from_slice(r.as_ref());
+        }
+        Self::from_vec(h, w, data)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.h && j < self.w, "index out of bounds");
+        &self.data[i * self.w + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.h && j < self.w, "index out of bounds");
+        &mut self.data[i * self.w + j]
+    }
+}
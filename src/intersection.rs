@@ -0,0 +1,181 @@
+#[allow(dead_code)]
+#[path = "geometry.rs"]
+pub mod geometry;
+
+use geometry::{ccw, projection, Ccw, Coord, Point, EPS};
+
+fn main() {
+    let p = |x, y| Point::new(x, y);
+    dbg!(segments_intersect(p(0, 0), p(2, 2), p(0, 2), p(2, 0)));
+    dbg!(segments_intersect(p(0, 0), p(1, 1), p(2, 2), p(3, 3)));
+    dbg!(segments_intersect(p(0, 0), p(2, 0), p(2, 0), p(2, 5)));
+    dbg!(segment_intersection(p(0, 0), p(2, 2), p(0, 2), p(2, 0)));
+    dbg!(segment_intersection(p(0, 0), p(3, 0), p(1, 0), p(5, 0)));
+    dbg!(segment_intersection(p(0, 0), p(1, 0), p(1, 0), p(5, 0)));
+    dbg!(segment_intersection(p(0, 0), p(1, 0), p(0, 1), p(1, 1)));
+    dbg!(line_intersection(p(0, 0), p(1, 1), p(3, 0), p(3, 1)));
+    dbg!(distance_point_segment(p(1, 1), p(0, 0), p(2, 0)));
+    dbg!(distance_point_segment(p(3, 1), p(0, 0), p(2, 0)));
+    dbg!(distance_segments(p(0, 0), p(1, 0), p(0, 2), p(1, 3)));
+
+    let c1 = Circle::new(Point::new(0.0, 0.0), 2.0);
+    let c2 = Circle::new(Point::new(2.0, 0.0), 2.0);
+    dbg!(circle_intersection(c1, c2));
+    dbg!(circle_intersection(
+        c1,
+        Circle::new(Point::new(3.0, 0.0), 1.0)
+    ));
+    dbg!(circle_intersection(
+        c1,
+        Circle::new(Point::new(0.5, 0.0), 1.0)
+    ));
+    dbg!(circle_line_intersection(
+        c1,
+        Point::new(-5.0, 1.0),
+        Point::new(5.0, 1.0)
+    ));
+    dbg!(circle_line_intersection(
+        c1,
+        Point::new(2.0, -1.0),
+        Point::new(2.0, 1.0)
+    ));
+}
+
+/// 点 `p` が線分 `ab` 上 (端点を含む) にあるか
+pub fn on_segment<T: Coord>(p: Point<T>, a: Point<T>, b: Point<T>) -> bool {
+    (b - a).cross(p - a).sign() == 0 && (a - p).dot(b - p).sign() <= 0
+}
+
+/// 線分 `ab` と線分 `cd` が共有点をもつか (端点での接触も含む)．端点が一致する線分 (点) でもよい
+pub fn segments_intersect<T: Coord>(a: Point<T>, b: Point<T>, c: Point<T>, d: Point<T>) -> bool {
+    if a == b {
+        return on_segment(a, c, d);
+    }
+    if c == d {
+        return on_segment(c, a, b);
+    }
+    // 反時計回りを 1，時計回りを -1，直線上の線分外を 2 または -2，線分上を 0 とすると，積が 0 以下
+    let val = |r| match r {
+        Ccw::CounterClockwise => 1,
+        Ccw::Clockwise => -1,
+        Ccw::OnlineBack => 2,
+        Ccw::OnlineFront => -2,
+        Ccw::OnSegment => 0,
+    };
+    val(ccw(a, b, c)) * val(ccw(a, b, d)) <= 0 && val(ccw(c, d, a)) * val(ccw(c, d, b)) <= 0
+}
+
+/// 直線 `ab` と直線 `cd` の交点．平行 (一致も含む) なら `None`
+pub fn line_intersection<T: Coord>(
+    a: Point<T>,
+    b: Point<T>,
+    c: Point<T>,
+    d: Point<T>,
+) -> Option<Point<f64>> {
+    let den = (b - a).cross(d - c);
+    if den.sign() == 0 {
+        return None;
+    }
+    let num = (c - a).cross(d - c);
+    Some(a.to_f64() + (b - a).to_f64() * (num.to_f64() / den.to_f64()))
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SegmentIntersection {
+    None,
+    Point(Point<f64>),
+    /// 同じ直線上で重なっている．重なっている部分の両端
+    Segment(Point<f64>, Point<f64>),
+}
+
+/// 線分 `ab` と線分 `cd` の共通部分
+pub fn segment_intersection<T: Coord>(
+    a: Point<T>,
+    b: Point<T>,
+    c: Point<T>,
+    d: Point<T>,
+) -> SegmentIntersection {
+    if !segments_intersect(a, b, c, d) {
+        return SegmentIntersection::None;
+    }
+    if let Some(p) = line_intersection(a, b, c, d) {
+        return SegmentIntersection::Point(p);
+    }
+    // 同じ直線上にある (点の場合を含む)．その直線の向きで並べて，真ん中の 2 点
+    let dir = if a != b { b - a } else { d - c };
+    let mut ps = [a, b, c, d];
+    ps.sort_by(|&p, &q| 0.cmp(&(q - p).dot(dir).sign()));
+    let (p, q) = (ps[1], ps[2]);
+    if p == q {
+        SegmentIntersection::Point(p.to_f64())
+    } else {
+        SegmentIntersection::Segment(p.to_f64(), q.to_f64())
+    }
+}
+
+/// 点 `p` と線分 `ab` の距離
+pub fn distance_point_segment<T: Coord>(p: Point<T>, a: Point<T>, b: Point<T>) -> f64 {
+    if (b - a).dot(p - a).sign() <= 0 {
+        return (p - a).to_f64().abs();
+    }
+    if (a - b).dot(p - b).sign() <= 0 {
+        return (p - b).to_f64().abs();
+    }
+    (b - a).cross(p - a).to_f64().abs() / (b - a).to_f64().abs()
+}
+
+/// 線分 `ab` と線分 `cd` の距離
+pub fn distance_segments<T: Coord>(a: Point<T>, b: Point<T>, c: Point<T>, d: Point<T>) -> f64 {
+    if segments_intersect(a, b, c, d) {
+        return 0.0;
+    }
+    distance_point_segment(a, c, d)
+        .min(distance_point_segment(b, c, d))
+        .min(distance_point_segment(c, a, b))
+        .min(distance_point_segment(d, a, b))
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Circle {
+    pub center: Point<f64>,
+    pub r: f64,
+}
+
+impl Circle {
+    pub fn new(center: Point<f64>, r: f64) -> Self {
+        Self { center, r }
+    }
+}
+
+/// 2 つの円周の交点．接していれば 1 点．中心が一致すれば (同じ円でも) 空
+pub fn circle_intersection(c1: Circle, c2: Circle) -> Vec<Point<f64>> {
+    let v = c2.center - c1.center;
+    let d = v.abs();
+    if d <= EPS || d > c1.r + c2.r + EPS || d < (c1.r - c2.r).abs() - EPS {
+        return vec![];
+    }
+    // c1 の中心から v の向きに x 進んだところで，垂直に ±h
+    let x = (d * d + c1.r * c1.r - c2.r * c2.r) / (2.0 * d);
+    let h = (c1.r * c1.r - x * x).max(0.0).sqrt();
+    let base = c1.center + v * (x / d);
+    if h <= EPS {
+        return vec![base];
+    }
+    let n = Point::new(-v.y, v.x) * (h / d);
+    vec![base + n, base - n]
+}
+
+/// 円周と直線 `ab` の交点．接していれば 1 点．`a != b`
+pub fn circle_line_intersection(c: Circle, a: Point<f64>, b: Point<f64>) -> Vec<Point<f64>> {
+    let h = projection(c.center, a, b);
+    let dist = (h - c.center).abs();
+    if dist > c.r + EPS {
+        return vec![];
+    }
+    let len = (c.r * c.r - dist * dist).max(0.0).sqrt();
+    if len <= EPS {
+        return vec![h];
+    }
+    let v = (b - a).unit() * len;
+    vec![h - v, h + v]
+}
@@ -0,0 +1,55 @@
+fn main() {
+    dbg!(inversion_count(&[3, 1, 4, 1, 5, 9, 2, 6]));
+    dbg!(inversion_count(&["b", "c", "a"]));
+    dbg!(
+        min_adjacent_swaps(&[2, 0, 1, 3], &[0, 1, 3, 2]),
+        min_adjacent_swaps(b"abcab", b"bacba"),
+        min_adjacent_swaps(b"aab", b"abb")
+    );
+}
+
+/// `i < j` かつ `a[i] > a[j]` となる組の個数．`O(n log n)`
+pub fn inversion_count<T: Ord>(a: &[T]) -> u64 {
+    // 座標圧縮して，右から見て自分より小さいものを Fenwick Tree で数える
+    let mut sorted: Vec<&T> = a.iter().collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let mut bit = vec![0u64; sorted.len() + 1];
+    let mut res = 0;
+    for x in a.iter().rev() {
+        let k = sorted.binary_search(&x).unwrap();
+        let mut i = k;
+        while i > 0 {
+            res += bit[i];
+            i &= i - 1;
+        }
+        let mut i = k + 1;
+        while i < bit.len() {
+            bit[i] += 1;
+            i += i & i.wrapping_neg();
+        }
+    }
+    res
+}
+
+/// 隣り合う要素の swap で `a` を `b` にする最小回数．`b` が `a` の並べ替えでなければ `None`
+///
+/// 同じ値は順番を変えずに対応させるのが最適．`a`, `b` が順列なら `a` と `b` の間の転倒数．
+pub fn min_adjacent_swaps<T: Ord>(a: &[T], b: &[T]) -> Option<u64> {
+    if a.len() != b.len() {
+        return None;
+    }
+    let mut ia: Vec<usize> = (0..a.len()).collect();
+    let mut ib: Vec<usize> = (0..b.len()).collect();
+    ia.sort_by_key(|&i| &a[i]);
+    ib.sort_by_key(|&i| &b[i]);
+    // a[i] の行き先
+    let mut to = vec![0; a.len()];
+    for (&i, &j) in ia.iter().zip(&ib) {
+        if a[i] != b[j] {
+            return None;
+        }
+        to[i] = j;
+    }
+    Some(inversion_count(&to))
+}
@@ -0,0 +1,167 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+fn main() {
+    let graph = vec![
+        vec![(1, 3), (2, 2)],
+        vec![(3, 4)],
+        vec![(1, 1), (3, 2), (4, 3)],
+        vec![(4, 2), (5, 1)],
+        vec![(5, 2)],
+        vec![(0, 1)],
+    ];
+    dbg!(k_shortest_paths(&graph, 0, 5, 5, false));
+    dbg!(k_shortest_paths(&graph, 0, 5, 5, true));
+    dbg!(k_shortest_simple_paths(&graph, 0, 5, 3));
+}
+
+/// `s` から `t` への路の長さを短い順に `k` 個まで．`simple` なら単純路に限る．
+pub fn k_shortest_paths(
+    graph: &[Vec<(usize, u64)>],
+    s: usize,
+    t: usize,
+    k: usize,
+    simple: bool,
+) -> Vec<u64> {
+    if simple {
+        k_shortest_simple_paths(graph, s, t, k)
+            .into_iter()
+            .map(|(d, _)| d)
+            .collect()
+    } else {
+        k_shortest_walks(graph, s, t, k)
+    }
+}
+
+/// 各頂点を `k` 回まで取り出す Dijkstra．同じ頂点を何度通ってもよい．
+pub fn k_shortest_walks(graph: &[Vec<(usize, u64)>], s: usize, t: usize, k: usize) -> Vec<u64> {
+    let mut count = vec![0; graph.len()];
+    let mut res = vec![];
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0, s)));
+    while let Some(Reverse((d, v))) = heap.pop() {
+        if count[v] == k {
+            continue;
+        }
+        count[v] += 1;
+        if v == t {
+            res.push(d);
+            if res.len() == k {
+                break;
+            }
+        }
+        for &(to, w) in &graph[v] {
+            if count[to] < k {
+                heap.push(Reverse((d + w, to)));
+            }
+        }
+    }
+    res
+}
+
+/// Yen のアルゴリズム．`(長さ, 頂点の列)` を短い順に `k` 個まで．
+pub fn k_shortest_simple_paths(
+    graph: &[Vec<(usize, u64)>],
+    s: usize,
+    t: usize,
+    k: usize,
+) -> Vec<(u64, Vec<usize>)> {
+    let n = graph.len();
+    // 路は (頂点, 隣接リストの添字) の列で持つ．多重辺を区別するため．
+    let mut found: Vec<(u64, Vec<(usize, usize)>)> = vec![];
+    let mut candidates = BinaryHeap::new();
+    let mut seen = HashSet::new();
+    let mut banned_vertex = vec![false; n];
+    let mut banned_edge = HashSet::new();
+    if k == 0 {
+        return vec![];
+    }
+    match dijkstra(graph, s, t, &banned_vertex, &banned_edge) {
+        Some(first) => found.push(first),
+        None => return vec![],
+    }
+    while found.len() < k {
+        let (_, last) = found.last().unwrap().clone();
+        let mut root_len = 0;
+        for j in 0..last.len() {
+            let spur = last[j].0;
+            for (_, path) in &found {
+                if path.len() > j && path[..j] == last[..j] {
+                    banned_edge.insert(path[j]);
+                }
+            }
+            for &(v, _) in &last[..j] {
+                banned_vertex[v] = true;
+            }
+            if let Some((d, spur_path)) = dijkstra(graph, spur, t, &banned_vertex, &banned_edge) {
+                let mut path = last[..j].to_vec();
+                path.extend(spur_path);
+                if seen.insert(path.clone()) {
+                    candidates.push(Reverse((root_len + d, path)));
+                }
+            }
+            for &(v, _) in &last[..j] {
+                banned_vertex[v] = false;
+            }
+            banned_edge.clear();
+            let (v, i) = last[j];
+            root_len += graph[v][i].1;
+        }
+        match candidates.pop() {
+            Some(Reverse(next)) => found.push(next),
+            None => break,
+        }
+    }
+    found
+        .into_iter()
+        .map(|(d, path)| {
+            let mut vertices: Vec<_> = path.iter().map(|&(v, _)| v).collect();
+            vertices.push(t);
+            (d, vertices)
+        })
+        .collect()
+}
+
+fn dijkstra(
+    graph: &[Vec<(usize, u64)>],
+    s: usize,
+    t: usize,
+    banned_vertex: &[bool],
+    banned_edge: &HashSet<(usize, usize)>,
+) -> Option<(u64, Vec<(usize, usize)>)> {
+    let n = graph.len();
+    let mut dist = vec![u64::MAX; n];
+    let mut prev = vec![(usize::MAX, usize::MAX); n];
+    let mut heap = BinaryHeap::new();
+    dist[s] = 0;
+    heap.push(Reverse((0, s)));
+    while let Some(Reverse((d, v))) = heap.pop() {
+        if d > dist[v] {
+            continue;
+        }
+        if v == t {
+            break;
+        }
+        for (i, &(to, w)) in graph[v].iter().enumerate() {
+            if banned_vertex[to] || banned_edge.contains(&(v, i)) {
+                continue;
+            }
+            if d + w < dist[to] {
+                dist[to] = d + w;
+                prev[to] = (v, i);
+                heap.push(Reverse((d + w, to)));
+            }
+        }
+    }
+    if dist[t] == u64::MAX {
+        return None;
+    }
+    let mut path = vec![];
+    let mut v = t;
+    while v != s {
+        path.push(prev[v]);
+        v = prev[v].0;
+    }
+    path.reverse();
+    Some((dist[t], path))
+}
@@ -0,0 +1,87 @@
+fn main() {
+    dbg!(kmp_failure(b"abacaba"));
+    let kmp = Kmp::new(b"aba".to_vec());
+    dbg!(kmp.find_all(b"abababacaba"));
+
+    // 1文字ずつ追加していく
+    let mut kmp = Kmp::new("いろ".chars().collect());
+    for c in "いろいいろは".chars() {
+        dbg!(c, kmp.push(c));
+    }
+    kmp.reset();
+    dbg!(kmp.state());
+}
+
+/// `fail[i]`: `pattern[..i]` の最長の (自身を除く) border の長さ．長さ `pattern.len() + 1`．
+pub fn kmp_failure<T: Eq>(pattern: &[T]) -> Vec<usize> {
+    let mut fail = vec![0; pattern.len() + 1];
+    let mut k = 0;
+    for i in 1..pattern.len() {
+        while k > 0 && pattern[k] != pattern[i] {
+            k = fail[k];
+        }
+        if pattern[k] == pattern[i] {
+            k += 1;
+        }
+        fail[i + 1] = k;
+    }
+    fail
+}
+
+pub struct Kmp<T> {
+    pattern: Vec<T>,
+    fail: Vec<usize>,
+    state: usize,
+}
+
+impl<T: Eq> Kmp<T> {
+    pub fn new(pattern: Vec<T>) -> Self {
+        assert!(!pattern.is_empty(), "empty pattern");
+        let fail = kmp_failure(&pattern);
+        Self {
+            pattern,
+            fail,
+            state: 0,
+        }
+    }
+    pub fn pattern(&self) -> &[T] {
+        &self.pattern
+    }
+    pub fn failure(&self) -> &[usize] {
+        &self.fail
+    }
+    /// 今までに追加した文字列の接尾辞であって `pattern` の接頭辞であるものの最大の長さ
+    pub fn state(&self) -> usize {
+        self.state
+    }
+    pub fn reset(&mut self) {
+        self.state = 0;
+    }
+    /// 状態 `state` で `c` を読んだ後の状態
+    pub fn next_state(&self, mut state: usize, c: &T) -> usize {
+        while state > 0 && (state == self.pattern.len() || self.pattern[state] != *c) {
+            state = self.fail[state];
+        }
+        if state < self.pattern.len() && self.pattern[state] == *c {
+            state += 1;
+        }
+        state
+    }
+    /// `c` を追加し，`pattern` で終わるようになったかを返す
+    pub fn push(&mut self, c: T) -> bool {
+        self.state = self.next_state(self.state, &c);
+        self.state == self.pattern.len()
+    }
+    /// `text` 中の `pattern` の出現位置 (始点) をすべて
+    pub fn find_all(&self, text: &[T]) -> Vec<usize> {
+        let mut res = vec![];
+        let mut state = 0;
+        for (i, c) in text.iter().enumerate() {
+            state = self.next_state(state, c);
+            if state == self.pattern.len() {
+                res.push(i + 1 - state);
+            }
+        }
+        res
+    }
+}
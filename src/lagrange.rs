@@ -0,0 +1,83 @@
+#[allow(dead_code)]
+#[path = "modint.rs"]
+pub mod modint;
+
+use modint::ModInt;
+
+fn main() {
+    type Mint = ModInt<998244353>;
+    // f(x) = x^2 + 1
+    let xs: Vec<Mint> = [1, 3, 4].into_iter().map(Mint::new).collect();
+    let ys: Vec<Mint> = xs.iter().map(|&x| x * x + Mint::new(1)).collect();
+    dbg!(
+        lagrange_interpolate(&xs, &ys, Mint::new(10)),
+        lagrange_interpolate(&xs, &ys, Mint::new(3))
+    );
+
+    // Σ_{i=1}^{n} i^k は n の k + 1 次式
+    let k = 10;
+    let mut ys = vec![Mint::new(0)];
+    for i in 1..=k + 1 {
+        ys.push(ys[i - 1] + Mint::from(i).pow(k as u64));
+    }
+    dbg!(lagrange_consecutive(&ys, Mint::from(1_000_000_000_000u64)));
+    dbg!(lagrange_consecutive(&ys, Mint::new(5)), ys[5]);
+}
+
+/// `f(xs[i]) = ys[i]` となる次数 `n - 1` 以下の `f` について `f(t)`．`xs` は相異なる．`O(n^2)`
+pub fn lagrange_interpolate<const M: u32>(
+    xs: &[ModInt<M>],
+    ys: &[ModInt<M>],
+    t: ModInt<M>,
+) -> ModInt<M> {
+    assert_eq!(xs.len(), ys.len());
+    let mut res = ModInt::raw(0);
+    for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+        let (mut num, mut den) = (ModInt::new(1), ModInt::new(1));
+        for (j, &xj) in xs.iter().enumerate() {
+            if i != j {
+                num *= t - xj;
+                den *= xi - xj;
+            }
+        }
+        res += yi * num / den;
+    }
+    res
+}
+
+/// `f(i) = ys[i]` (`0 <= i < n`) となる次数 `n - 1` 以下の `f` について `f(t)`．`O(n)`．`M` は `n` 以上の素数
+pub fn lagrange_consecutive<const M: u32>(ys: &[ModInt<M>], t: ModInt<M>) -> ModInt<M> {
+    let n = ys.len();
+    if n == 0 {
+        return ModInt::raw(0);
+    }
+    if (t.val() as usize) < n {
+        return ys[t.val() as usize];
+    }
+    // left[i] = Π_{j < i} (t - j)，right[i] = Π_{j > i} (t - j)
+    let mut left = vec![ModInt::new(1); n + 1];
+    for i in 0..n {
+        left[i + 1] = left[i] * (t - ModInt::from(i));
+    }
+    let mut right = vec![ModInt::new(1); n + 1];
+    for i in (0..n).rev() {
+        right[i] = right[i + 1] * (t - ModInt::from(i));
+    }
+    let mut fact_inv = vec![ModInt::new(1); n];
+    let fact = (1..n).fold(ModInt::new(1), |acc, i| acc * ModInt::from(i));
+    fact_inv[n - 1] = fact.inv();
+    for i in (1..n).rev() {
+        fact_inv[i - 1] = fact_inv[i] * ModInt::from(i);
+    }
+    // Π_{j ≠ i} (i - j) = i! (n - 1 - i)! (-1)^{n - 1 - i}
+    let mut res = ModInt::raw(0);
+    for i in 0..n {
+        let term = ys[i] * left[i] * right[i + 1] * fact_inv[i] * fact_inv[n - 1 - i];
+        if (n - 1 - i).is_multiple_of(2) {
+            res += term;
+        } else {
+            res -= term;
+        }
+    }
+    res
+}
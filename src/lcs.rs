@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+
+fn main() {
+    dbg!(lcs_length(b"abcbdab", b"bdcaba"));
+    dbg!(lcs_length_naive(b"abcbdab", b"bdcaba"));
+    let a: Vec<u32> = (0..100_000u64).map(|i| (i * i % 1000) as u32).collect();
+    let b: Vec<u32> = (0..100_000).map(|i| i * 7 % 1000).collect();
+    dbg!(lcs_length(&a, &b));
+}
+
+/// 最長共通部分列の長さ．`O(nm / 64 + (n + m) log n)`
+pub fn lcs_length<T: Ord>(a: &[T], b: &[T]) -> usize {
+    let w = a.len().div_ceil(64);
+    // masks[c]: a[i] == c となる i のビットを立てたもの
+    let mut masks: BTreeMap<&T, Vec<u64>> = BTreeMap::new();
+    for (i, c) in a.iter().enumerate() {
+        masks.entry(c).or_insert_with(|| vec![0; w])[i / 64] |= 1 << (i % 64);
+    }
+    // s の i ビット目: dp[i + 1] - dp[i] (dp[i]: a[..i] と b の今までの部分の LCS の長さ)
+    let mut s = vec![0u64; w];
+    for c in b {
+        let Some(m) = masks.get(c) else {
+            continue;
+        };
+        // s = x & !(x - ((s << 1) | 1)) where x = m | s
+        let mut shift_carry = 1;
+        let mut borrow = 0;
+        for (v, &m) in s.iter_mut().zip(m) {
+            let x = m | *v;
+            let y = (*v << 1) | shift_carry;
+            shift_carry = *v >> 63;
+            let (d, b1) = x.overflowing_sub(y);
+            let (d, b2) = d.overflowing_sub(borrow);
+            borrow = (b1 || b2) as u64;
+            *v = x & !d;
+        }
+    }
+    s.iter().map(|x| x.count_ones() as usize).sum()
+}
+
+/// `O(nm)` の DP
+pub fn lcs_length_naive<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let mut dp = vec![0; b.len() + 1];
+    for x in a {
+        let mut prev = 0;
+        for (j, y) in b.iter().enumerate() {
+            let tmp = dp[j + 1];
+            dp[j + 1] = if x == y {
+                prev + 1
+            } else {
+                dp[j + 1].max(dp[j])
+            };
+            prev = tmp;
+        }
+    }
+    dp[b.len()]
+}
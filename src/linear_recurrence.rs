@@ -0,0 +1,85 @@
+#[allow(dead_code)]
+#[path = "convolution.rs"]
+pub mod convolution;
+
+use convolution::{convolution, modint::ModInt};
+
+fn main() {
+    type Mint = ModInt<998244353>;
+    // Fibonacci
+    let fib: Vec<Mint> = [0, 1, 1, 2, 3, 5, 8, 13]
+        .into_iter()
+        .map(Mint::new)
+        .collect();
+    let rec = berlekamp_massey(&fib);
+    dbg!(&rec);
+    dbg!(kth_term(&rec, &fib[..rec.len()], 10));
+    dbg!(kth_term(&rec, &fib[..rec.len()], 1_000_000_000_000_000_000));
+
+    // 小さい場合を全探索して漸化式を推測してから，大きな k の項を求める
+    let seq: Vec<Mint> = (0..20u32)
+        .map(|n| Mint::new(n * n * n + 2u32.pow(n)))
+        .collect();
+    let rec = berlekamp_massey(&seq);
+    dbg!(rec.len(), kth_term(&rec, &seq[..rec.len()], 19), seq[19]);
+}
+
+/// `a[i] = Σ_{j = 1}^{d} c[j - 1] a[i - j]` (`i >= d`) を満たす最短の `c`．`M` は素数
+pub fn berlekamp_massey<const M: u32>(a: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    // c: 今の漸化式 (1 - Σ c_j x^j の係数)，b: 最後に長さが変わる直前の漸化式
+    let mut c = vec![ModInt::new(1)];
+    let mut b = vec![ModInt::new(1)];
+    let mut last_delta = ModInt::new(1);
+    let mut shift = 1;
+    for i in 0..a.len() {
+        let delta: ModInt<M> = c.iter().enumerate().map(|(j, &cj)| cj * a[i - j]).sum();
+        if delta == ModInt::raw(0) {
+            shift += 1;
+            continue;
+        }
+        let coef = delta / last_delta;
+        let prev = c.clone();
+        if c.len() < b.len() + shift {
+            c.resize(b.len() + shift, ModInt::raw(0));
+        }
+        for (j, &bj) in b.iter().enumerate() {
+            c[j + shift] -= coef * bj;
+        }
+        if 2 * (prev.len() - 1) <= i {
+            b = prev;
+            last_delta = delta;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+    c[1..].iter().map(|&x| -x).collect()
+}
+
+/// `a[i] = Σ_{j = 1}^{d} rec[j - 1] a[i - j]`，`a[..d] = init` のときの `a[k]`．Bostan–Mori 法で `O(d log d log k)`
+pub fn kth_term<const M: u32>(rec: &[ModInt<M>], init: &[ModInt<M>], mut k: u64) -> ModInt<M> {
+    let d = rec.len();
+    assert_eq!(init.len(), d);
+    if d == 0 {
+        return ModInt::raw(0);
+    }
+    // 母関数 P / Q，Q = 1 - Σ rec[j - 1] x^j，P = init Q mod x^d
+    let mut q = vec![ModInt::new(1)];
+    q.extend(rec.iter().map(|&x| -x));
+    let mut p = convolution(init, &q);
+    p.truncate(d);
+    while k > 0 {
+        // P(x) / Q(x) = P(x) Q(-x) / Q(x) Q(-x) で，分母は x^2 の多項式になる
+        let q_neg: Vec<ModInt<M>> = q
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| if i % 2 == 0 { x } else { -x })
+            .collect();
+        let u = convolution(&p, &q_neg);
+        let v = convolution(&q, &q_neg);
+        p = u.into_iter().skip((k % 2) as usize).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        k /= 2;
+    }
+    p.first().copied().unwrap_or_default() / q[0]
+}
@@ -0,0 +1,222 @@
+fn main() {
+    // 0 - 1 - 2 - 0 が三角形，2 - 3 が橋，3 - 4 - 5 - 3 が三角形，5 - 6 が橋
+    let edges = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (5, 6),
+    ];
+    let ll = LowLink::new(7, &edges);
+    dbg!(ll.ord(), ll.low());
+    dbg!(ll.bridges(), ll.articulation_points());
+    dbg!(ll.is_bridge(3), ll.is_articulation_point(2));
+    dbg!(ll.two_edge_connected_components());
+    dbg!(ll.biconnected_components());
+    dbg!(ll.block_cut_tree());
+
+    // 多重辺と自己ループ
+    let ll = LowLink::new(3, &[(0, 1), (0, 1), (1, 2), (2, 2)]);
+    dbg!(ll.bridges(), ll.articulation_points());
+    dbg!(ll.biconnected_components());
+}
+
+pub struct LowLink {
+    edges: Vec<(usize, usize)>,
+    ord: Vec<usize>,
+    low: Vec<usize>,
+    bridges: Vec<usize>,
+    is_bridge: Vec<bool>,
+    articulation_points: Vec<usize>,
+    is_articulation_point: Vec<bool>,
+    // DFS で訪れた順の頂点と，その親の辺
+    order: Vec<usize>,
+    parent_edge: Vec<Option<usize>>,
+    blocks: Vec<Vec<usize>>,
+}
+
+impl LowLink {
+    pub fn new(n: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adj = vec![vec![]; n];
+        for (i, &(u, v)) in edges.iter().enumerate() {
+            assert!(u < n && v < n, "index out: ({}, {})/{}", u, v, n);
+            adj[u].push((v, i));
+            adj[v].push((u, i));
+        }
+        let mut ord = vec![usize::MAX; n];
+        let mut low = vec![usize::MAX; n];
+        let mut parent_edge = vec![None; n];
+        let mut is_bridge = vec![false; edges.len()];
+        let mut is_articulation_point = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut blocks = vec![];
+        let mut iter = vec![0; n];
+        let mut children = vec![0; n];
+        let mut stack = vec![];
+        let mut edge_stack = vec![];
+        for root in 0..n {
+            if ord[root] != usize::MAX {
+                continue;
+            }
+            ord[root] = order.len();
+            low[root] = order.len();
+            order.push(root);
+            stack.push(root);
+            while let Some(&v) = stack.last() {
+                if let Some(&(to, e)) = adj[v].get(iter[v]) {
+                    iter[v] += 1;
+                    if Some(e) == parent_edge[v] {
+                        continue;
+                    }
+                    if ord[to] == usize::MAX {
+                        parent_edge[to] = Some(e);
+                        ord[to] = order.len();
+                        low[to] = order.len();
+                        order.push(to);
+                        stack.push(to);
+                        edge_stack.push(e);
+                        children[v] += 1;
+                    } else if ord[to] < ord[v] {
+                        low[v] = low[v].min(ord[to]);
+                        edge_stack.push(e);
+                    }
+                    continue;
+                }
+                stack.pop();
+                let p = match stack.last() {
+                    Some(&p) => p,
+                    None => break,
+                };
+                low[p] = low[p].min(low[v]);
+                if low[v] > ord[p] {
+                    is_bridge[parent_edge[v].unwrap()] = true;
+                }
+                if low[v] >= ord[p] {
+                    if p != root || children[p] >= 2 {
+                        is_articulation_point[p] = true;
+                    }
+                    let mut block = vec![];
+                    while let Some(e) = edge_stack.pop() {
+                        block.push(e);
+                        if Some(e) == parent_edge[v] {
+                            break;
+                        }
+                    }
+                    blocks.push(block);
+                }
+            }
+        }
+        let bridges = (0..edges.len()).filter(|&e| is_bridge[e]).collect();
+        let articulation_points = (0..n).filter(|&v| is_articulation_point[v]).collect();
+        Self {
+            edges: edges.to_vec(),
+            ord,
+            low,
+            bridges,
+            is_bridge,
+            articulation_points,
+            is_articulation_point,
+            order,
+            parent_edge,
+            blocks,
+        }
+    }
+    pub fn ord(&self) -> &[usize] {
+        &self.ord
+    }
+    pub fn low(&self) -> &[usize] {
+        &self.low
+    }
+    pub fn bridges(&self) -> &[usize] {
+        &self.bridges
+    }
+    pub fn is_bridge(&self, e: usize) -> bool {
+        self.is_bridge[e]
+    }
+    pub fn articulation_points(&self) -> &[usize] {
+        &self.articulation_points
+    }
+    pub fn is_articulation_point(&self, v: usize) -> bool {
+        self.is_articulation_point[v]
+    }
+    /// (成分の個数, 各頂点の成分番号)
+    pub fn two_edge_connected_components(&self) -> (usize, Vec<usize>) {
+        let mut comp = vec![usize::MAX; self.ord.len()];
+        let mut count = 0;
+        for &v in &self.order {
+            match self.parent_edge[v] {
+                Some(e) if !self.is_bridge[e] => {
+                    let (a, b) = self.edges[e];
+                    comp[v] = comp[a ^ b ^ v];
+                }
+                _ => {
+                    comp[v] = count;
+                    count += 1;
+                }
+            }
+        }
+        (count, comp)
+    }
+    /// 各二重頂点連結成分に含まれる辺の番号．自己ループはどの成分にも含まれない．
+    pub fn biconnected_components(&self) -> &[Vec<usize>] {
+        &self.blocks
+    }
+    /// 各二重頂点連結成分に含まれる頂点．孤立点 (自己ループのみの頂点を含む) もそれ単体でひとつの成分とする．
+    pub fn biconnected_component_vertices(&self) -> Vec<Vec<usize>> {
+        let n = self.ord.len();
+        let mut used = vec![false; n];
+        let mut res = vec![];
+        for block in &self.blocks {
+            let mut vs = vec![];
+            for &e in block {
+                let (a, b) = self.edges[e];
+                for v in [a, b] {
+                    if !used[v] {
+                        used[v] = true;
+                        vs.push(v);
+                    }
+                }
+            }
+            for &v in &vs {
+                used[v] = false;
+            }
+            res.push(vs);
+        }
+        let mut has_edge = vec![false; n];
+        for &(a, b) in &self.edges {
+            if a != b {
+                has_edge[a] = true;
+                has_edge[b] = true;
+            }
+        }
+        res.extend((0..n).filter(|&v| !has_edge[v]).map(|v| vec![v]));
+        res
+    }
+    /// (各頂点に対応する頂点番号, 森の隣接リスト)
+    ///
+    /// 頂点 `0..b` が二重頂点連結成分 (`biconnected_component_vertices` の順)，
+    /// `b..` が関節点に対応する．関節点でない頂点は，それを含む唯一の成分に対応する．
+    pub fn block_cut_tree(&self) -> (Vec<usize>, Vec<Vec<usize>>) {
+        let blocks = self.biconnected_component_vertices();
+        let b = blocks.len();
+        let mut id = vec![usize::MAX; self.ord.len()];
+        for (i, &v) in self.articulation_points.iter().enumerate() {
+            id[v] = b + i;
+        }
+        let mut tree = vec![vec![]; b + self.articulation_points.len()];
+        for (i, block) in blocks.iter().enumerate() {
+            for &v in block {
+                if self.is_articulation_point[v] {
+                    tree[i].push(id[v]);
+                    tree[id[v]].push(i);
+                } else {
+                    id[v] = i;
+                }
+            }
+        }
+        (id, tree)
+    }
+}
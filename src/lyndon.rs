@@ -0,0 +1,65 @@
+use std::ops::Range;
+
+fn main() {
+    let s = b"banananaba";
+    let factors = lyndon_factorization(s);
+    dbg!(&factors);
+    for r in factors {
+        dbg!(std::str::from_utf8(&s[r]).unwrap());
+    }
+    dbg!(least_rotation(b"bcabca"), least_rotation(&[3, 1, 2, 1, 2]));
+    dbg!(least_rotation::<u8>(&[]));
+}
+
+/// Duval のアルゴリズム．Lyndon 分解の各因子の区間を前から順に
+pub fn lyndon_factorization<T: Ord>(s: &[T]) -> Vec<Range<usize>> {
+    let n = s.len();
+    let mut res = vec![];
+    let mut i = 0;
+    while i < n {
+        let (mut j, mut k) = (i + 1, i);
+        while j < n && s[k] <= s[j] {
+            if s[k] < s[j] {
+                k = i;
+            } else {
+                k += 1;
+            }
+            j += 1;
+        }
+        while i <= k {
+            res.push(i..i + j - k);
+            i += j - k;
+        }
+    }
+    res
+}
+
+/// Booth のアルゴリズム．`s[k..] + s[..k]` が辞書順最小となる最小の `k`
+pub fn least_rotation<T: Ord>(s: &[T]) -> usize {
+    let n = s.len();
+    if n == 0 {
+        return 0;
+    }
+    let at = |i: usize| &s[i % n];
+    // f[i]: (s + s)[k..=k + i] の最長の border の長さ - 1 (なければ usize::MAX)
+    let mut f = vec![usize::MAX; 2 * n];
+    let mut k = 0;
+    for j in 1..2 * n {
+        let mut i = f[j - k - 1];
+        while i != usize::MAX && at(j) != at(k + i + 1) {
+            if at(j) < at(k + i + 1) {
+                k = j - i - 1;
+            }
+            i = f[i];
+        }
+        if i == usize::MAX && at(j) != at(k) {
+            if at(j) < at(k) {
+                k = j;
+            }
+            f[j - k] = usize::MAX;
+        } else {
+            f[j - k] = i.wrapping_add(1);
+        }
+    }
+    k
+}
@@ -0,0 +1,87 @@
+fn main() {
+    let s = b"abaaababa";
+    dbg!(manacher_odd(s));
+    dbg!(manacher(s));
+    let p = Palindromes::new(s);
+    dbg!(
+        p.is_palindrome(1, 6),
+        p.is_palindrome(0, 4),
+        p.is_palindrome(3, 3)
+    );
+    dbg!(p.longest(), p.count());
+}
+
+/// `rad[i]`: `s[i + 1 - r..i + r]` が回文となる最大の `r`
+pub fn manacher_odd<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut rad = vec![0; n];
+    let (mut i, mut j) = (0, 0);
+    while i < n {
+        while i >= j && i + j < n && s[i - j] == s[i + j] {
+            j += 1;
+        }
+        rad[i] = j;
+        let mut k = 1;
+        while i >= k && k + rad[i - k] < j {
+            rad[i + k] = rad[i - k];
+            k += 1;
+        }
+        i += k;
+        j -= k;
+    }
+    rad
+}
+
+/// 長さ `2n - 1`．`len[i]` は，`i` が偶数なら `s[i / 2]` を，奇数なら `s[i / 2]` と `s[i / 2 + 1]` の間を中心とする最長の回文の長さ
+pub fn manacher<T: Eq>(s: &[T]) -> Vec<usize> {
+    if s.is_empty() {
+        return vec![];
+    }
+    let t: Vec<Option<&T>> = (0..2 * s.len() - 1)
+        .map(|i| if i % 2 == 0 { Some(&s[i / 2]) } else { None })
+        .collect();
+    manacher_odd(&t)
+        .into_iter()
+        .enumerate()
+        .map(|(i, r)| {
+            // t[i + 1 - r..i + r] に含まれる偶数番目の個数
+            let (lo, hi) = (i + 1 - r, i + r - 1);
+            hi / 2 + 1 - lo.div_ceil(2)
+        })
+        .collect()
+}
+
+pub struct Palindromes {
+    len: Vec<usize>,
+}
+
+impl Palindromes {
+    pub fn new<T: Eq>(s: &[T]) -> Self {
+        Self { len: manacher(s) }
+    }
+    /// `manacher` の結果
+    pub fn lengths(&self) -> &[usize] {
+        &self.len
+    }
+    /// `s[l..r]` が回文か
+    pub fn is_palindrome(&self, l: usize, r: usize) -> bool {
+        assert!(l <= r, "invalid range: {}..{}", l, r);
+        assert!(r <= self.len.len().div_ceil(2), "index out");
+        l == r || self.len[l + r - 1] >= r - l
+    }
+    /// 最長の回文 `l..r`．複数あれば最も左のもの．
+    pub fn longest(&self) -> (usize, usize) {
+        let mut best = (0, 0);
+        for (i, &len) in self.len.iter().enumerate() {
+            if len > best.1 - best.0 {
+                let l = (i + 1 - len) / 2;
+                best = (l, l + len);
+            }
+        }
+        best
+    }
+    /// 回文である (空でない) 部分文字列の個数．位置が異なれば別に数える．
+    pub fn count(&self) -> u64 {
+        self.len.iter().map(|&len| (len as u64).div_ceil(2)).sum()
+    }
+}
@@ -0,0 +1,365 @@
+#[allow(dead_code)]
+#[path = "modint.rs"]
+pub mod modint;
+
+use modint::ModInt;
+use std::ops::{Index, IndexMut, Mul};
+
+fn main() {
+    // Fibonacci
+    let a =
+        Matrix::<Mod998244353>::from_vec(vec![vec![1.into(), 1.into()], vec![1.into(), 0.into()]]);
+    dbg!(a.pow(1_000_000_000_000_000_000)[(0, 1)]);
+
+    let a = Matrix::<F64>::from_vec(vec![
+        vec![2.0, 1.0, -1.0],
+        vec![-3.0, -1.0, 2.0],
+        vec![-2.0, 1.0, 2.0],
+    ]);
+    dbg!(a.determinant(), a.rank());
+    dbg!(a.solve_linear_system(&[8.0, -11.0, -3.0]));
+    let inv = a.inverse().unwrap();
+    dbg!(&(&a * &inv).rows());
+
+    // 解が1次元の空間をなす
+    let a = Matrix::<Mod998244353>::from_vec(vec![
+        vec![1.into(), 2.into(), 3.into()],
+        vec![2.into(), 4.into(), 6.into()],
+    ]);
+    dbg!(a.rank(), a.solve_linear_system(&[1.into(), 2.into()]));
+    dbg!(a.solve_linear_system(&[1.into(), 3.into()]));
+
+    // 最短経路 (辺の本数がちょうど k)
+    let inf = u64::MAX;
+    let g = Matrix::<MinPlus>::from_vec(vec![
+        vec![inf, 1, inf],
+        vec![inf, inf, 2],
+        vec![3, inf, inf],
+    ]);
+    dbg!(g.pow(4).rows());
+}
+
+/// 半環．`add` は可換で，`zero` は `mul` について零元
+pub trait Semiring {
+    type Item: Clone;
+    fn zero() -> Self::Item;
+    fn one() -> Self::Item;
+    fn add(a: &Self::Item, b: &Self::Item) -> Self::Item;
+    fn mul(a: &Self::Item, b: &Self::Item) -> Self::Item;
+}
+
+pub trait Field: Semiring {
+    fn sub(a: &Self::Item, b: &Self::Item) -> Self::Item;
+    fn div(a: &Self::Item, b: &Self::Item) -> Self::Item;
+    fn is_zero(a: &Self::Item) -> bool;
+    /// 掃き出し法でピボットを選ぶときの優先度 (大きいほど優先)
+    fn magnitude(a: &Self::Item) -> f64;
+}
+
+pub enum Mod<const M: u32> {}
+
+pub type Mod998244353 = Mod<998244353>;
+pub type Mod1000000007 = Mod<1000000007>;
+
+impl<const M: u32> Semiring for Mod<M> {
+    type Item = ModInt<M>;
+    fn zero() -> ModInt<M> {
+        ModInt::raw(0)
+    }
+    fn one() -> ModInt<M> {
+        ModInt::new(1)
+    }
+    fn add(a: &ModInt<M>, b: &ModInt<M>) -> ModInt<M> {
+        a + b
+    }
+    fn mul(a: &ModInt<M>, b: &ModInt<M>) -> ModInt<M> {
+        a * b
+    }
+}
+
+/// `M` は素数
+impl<const M: u32> Field for Mod<M> {
+    fn sub(a: &ModInt<M>, b: &ModInt<M>) -> ModInt<M> {
+        a - b
+    }
+    fn div(a: &ModInt<M>, b: &ModInt<M>) -> ModInt<M> {
+        a / b
+    }
+    fn is_zero(a: &ModInt<M>) -> bool {
+        a.val() == 0
+    }
+    fn magnitude(a: &ModInt<M>) -> f64 {
+        if a.val() == 0 {
+            0.0
+        } else {
+            1.0
+        }
+    }
+}
+
+pub enum F64 {}
+
+impl F64 {
+    /// 絶対値がこれ以下なら `0` とみなす
+    pub const EPS: f64 = 1e-9;
+}
+
+impl Semiring for F64 {
+    type Item = f64;
+    fn zero() -> f64 {
+        0.0
+    }
+    fn one() -> f64 {
+        1.0
+    }
+    fn add(a: &f64, b: &f64) -> f64 {
+        a + b
+    }
+    fn mul(a: &f64, b: &f64) -> f64 {
+        a * b
+    }
+}
+
+impl Field for F64 {
+    fn sub(a: &f64, b: &f64) -> f64 {
+        a - b
+    }
+    fn div(a: &f64, b: &f64) -> f64 {
+        a / b
+    }
+    fn is_zero(a: &f64) -> bool {
+        a.abs() <= Self::EPS
+    }
+    fn magnitude(a: &f64) -> f64 {
+        a.abs()
+    }
+}
+
+/// `(min, +)`．`u64::MAX` が無限大
+pub enum MinPlus {}
+
+impl Semiring for MinPlus {
+    type Item = u64;
+    fn zero() -> u64 {
+        u64::MAX
+    }
+    fn one() -> u64 {
+        0
+    }
+    fn add(a: &u64, b: &u64) -> u64 {
+        *a.min(b)
+    }
+    fn mul(a: &u64, b: &u64) -> u64 {
+        if *a == u64::MAX || *b == u64::MAX {
+            u64::MAX
+        } else {
+            a + b
+        }
+    }
+}
+
+pub struct Matrix<T: Semiring> {
+    h: usize,
+    w: usize,
+    a: Vec<Vec<T::Item>>,
+}
+
+impl<T: Semiring> Clone for Matrix<T> {
+    fn clone(&self) -> Self {
+        Self {
+            h: self.h,
+            w: self.w,
+            a: self.a.clone(),
+        }
+    }
+}
+
+impl<T: Semiring> std::fmt::Debug for Matrix<T>
+where
+    T::Item: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.a.fmt(f)
+    }
+}
+
+impl<T: Semiring> Index<(usize, usize)> for Matrix<T> {
+    type Output = T::Item;
+    fn index(&self, (i, j): (usize, usize)) -> &T::Item {
+        &self.a[i][j]
+    }
+}
+
+impl<T: Semiring> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T::Item {
+        &mut self.a[i][j]
+    }
+}
+
+impl<T: Semiring> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, rhs: Self) -> Matrix<T> {
+        assert_eq!(self.w, rhs.h);
+        let mut res = Matrix::zeros(self.h, rhs.w);
+        for i in 0..self.h {
+            for k in 0..self.w {
+                let x = &self.a[i][k];
+                for j in 0..rhs.w {
+                    res.a[i][j] = T::add(&res.a[i][j], &T::mul(x, &rhs.a[k][j]));
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<T: Semiring> Matrix<T> {
+    pub fn zeros(h: usize, w: usize) -> Self {
+        Self {
+            h,
+            w,
+            a: vec![vec![T::zero(); w]; h],
+        }
+    }
+    pub fn identity(n: usize) -> Self {
+        let mut res = Self::zeros(n, n);
+        for i in 0..n {
+            res.a[i][i] = T::one();
+        }
+        res
+    }
+    /// 各行の長さは等しい
+    pub fn from_vec(a: Vec<Vec<T::Item>>) -> Self {
+        let h = a.len();
+        let w = a.first().map_or(0, |row| row.len());
+        assert!(a.iter().all(|row| row.len() == w));
+        Self { h, w, a }
+    }
+    pub fn h(&self) -> usize {
+        self.h
+    }
+    pub fn w(&self) -> usize {
+        self.w
+    }
+    pub fn rows(&self) -> &[Vec<T::Item>] {
+        &self.a
+    }
+    pub fn transpose(&self) -> Self {
+        Self {
+            h: self.w,
+            w: self.h,
+            a: (0..self.w)
+                .map(|j| (0..self.h).map(|i| self.a[i][j].clone()).collect())
+                .collect(),
+        }
+    }
+    /// 正方行列の `k` 乗
+    pub fn pow(&self, mut k: u64) -> Self {
+        assert_eq!(self.h, self.w);
+        let mut res = Self::identity(self.h);
+        let mut base = self.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                res = &res * &base;
+            }
+            base = &base * &base;
+            k >>= 1;
+        }
+        res
+    }
+}
+
+/// 左から `cols` 列について掃き出し，被約階段形にする．ピボットの列と，正方部分の行列式を返す
+fn gauss<T: Field>(a: &mut [Vec<T::Item>], cols: usize) -> (Vec<usize>, T::Item) {
+    let h = a.len();
+    let mut pivots = vec![];
+    let mut det = T::one();
+    for c in 0..cols {
+        let rank = pivots.len();
+        let Some(p) = (rank..h)
+            .filter(|&i| !T::is_zero(&a[i][c]))
+            .max_by(|&i, &j| T::magnitude(&a[i][c]).total_cmp(&T::magnitude(&a[j][c])))
+        else {
+            det = T::zero();
+            continue;
+        };
+        if p != rank {
+            a.swap(p, rank);
+            det = T::sub(&T::zero(), &det);
+        }
+        let pivot = a[rank][c].clone();
+        det = T::mul(&det, &pivot);
+        for x in &mut a[rank][c..] {
+            *x = T::div(x, &pivot);
+        }
+        let pivot_row = a[rank].clone();
+        for (i, row) in a.iter_mut().enumerate() {
+            if i == rank || T::is_zero(&row[c]) {
+                continue;
+            }
+            let f = row[c].clone();
+            for (x, y) in row[c..].iter_mut().zip(&pivot_row[c..]) {
+                *x = T::sub(x, &T::mul(&f, y));
+            }
+        }
+        pivots.push(c);
+    }
+    (pivots, det)
+}
+
+impl<T: Field> Matrix<T> {
+    pub fn determinant(&self) -> T::Item {
+        assert_eq!(self.h, self.w);
+        gauss::<T>(&mut self.a.clone(), self.w).1
+    }
+    pub fn rank(&self) -> usize {
+        gauss::<T>(&mut self.a.clone(), self.w).0.len()
+    }
+    /// 正則でなければ `None`
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut a = self.a.clone();
+        for (i, row) in a.iter_mut().enumerate() {
+            row.extend((0..n).map(|j| if i == j { T::one() } else { T::zero() }));
+        }
+        if gauss::<T>(&mut a, n).0.len() < n {
+            return None;
+        }
+        Some(Self::from_vec(
+            a.into_iter().map(|row| row[n..].to_vec()).collect(),
+        ))
+    }
+    /// `Ax = b` の解のひとつと，`Ax = 0` の解空間の基底．解がなければ `None`
+    #[allow(clippy::type_complexity)]
+    pub fn solve_linear_system(&self, b: &[T::Item]) -> Option<(Vec<T::Item>, Vec<Vec<T::Item>>)> {
+        assert_eq!(self.h, b.len());
+        let w = self.w;
+        let mut a = self.a.clone();
+        for (row, x) in a.iter_mut().zip(b) {
+            row.push(x.clone());
+        }
+        let (pivots, _) = gauss::<T>(&mut a, w);
+        if a[pivots.len()..].iter().any(|row| !T::is_zero(&row[w])) {
+            return None;
+        }
+        let mut x = vec![T::zero(); w];
+        for (i, &c) in pivots.iter().enumerate() {
+            x[c] = a[i][w].clone();
+        }
+        let mut is_pivot = vec![false; w];
+        pivots.iter().for_each(|&c| is_pivot[c] = true);
+        let basis = (0..w)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut v = vec![T::zero(); w];
+                v[f] = T::one();
+                for (i, &c) in pivots.iter().enumerate() {
+                    v[c] = T::sub(&T::zero(), &a[i][f]);
+                }
+                v
+            })
+            .collect();
+        Some((x, basis))
+    }
+}
@@ -0,0 +1,132 @@
+fn main() {
+    let mut g = MaxFlow::new(4);
+    g.add_edge(0, 1, 3);
+    g.add_edge(0, 2, 2);
+    g.add_edge(1, 2, 1);
+    g.add_edge(1, 3, 1);
+    let e = g.add_edge(2, 3, 4);
+    dbg!(g.flow(0, 3));
+    dbg!(g.edge(e));
+    dbg!(g.min_cut(0));
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub cap: i64,
+    pub flow: i64,
+}
+
+#[derive(Clone, Copy)]
+struct InnerEdge {
+    to: usize,
+    rev: usize,
+    cap: i64,
+}
+
+/// Dinic 法
+pub struct MaxFlow {
+    graph: Vec<Vec<InnerEdge>>,
+    pos: Vec<(usize, usize)>,
+}
+
+impl MaxFlow {
+    pub fn new(n: usize) -> Self {
+        Self {
+            graph: (0..n).map(|_| vec![]).collect(),
+            pos: vec![],
+        }
+    }
+    pub fn add_edge(&mut self, from: usize, to: usize, cap: i64) -> usize {
+        let n = self.graph.len();
+        assert!(from < n && to < n, "index out: ({}, {})/{}", from, to, n);
+        assert!(cap >= 0, "negative capacity: {}", cap);
+        let rev_to = self.graph[to].len() + if from == to { 1 } else { 0 };
+        let rev_from = self.graph[from].len();
+        self.pos.push((from, rev_from));
+        self.graph[from].push(InnerEdge {
+            to,
+            rev: rev_to,
+            cap,
+        });
+        self.graph[to].push(InnerEdge {
+            to: from,
+            rev: rev_from,
+            cap: 0,
+        });
+        self.pos.len() - 1
+    }
+    pub fn edge(&self, i: usize) -> Edge {
+        let (from, j) = self.pos[i];
+        let e = &self.graph[from][j];
+        let r = &self.graph[e.to][e.rev];
+        Edge {
+            from,
+            to: e.to,
+            cap: e.cap + r.cap,
+            flow: r.cap,
+        }
+    }
+    pub fn flow(&mut self, s: usize, t: usize) -> i64 {
+        self.flow_with_limit(s, t, i64::MAX)
+    }
+    pub fn flow_with_limit(&mut self, s: usize, t: usize, limit: i64) -> i64 {
+        assert_ne!(s, t);
+        let n = self.graph.len();
+        let mut flow = 0;
+        while flow < limit {
+            let level = self.bfs(s);
+            if level[t] == usize::MAX {
+                break;
+            }
+            let mut iter = vec![0; n];
+            loop {
+                let f = self.dfs(s, t, limit - flow, &level, &mut iter);
+                if f == 0 {
+                    break;
+                }
+                flow += f;
+            }
+        }
+        flow
+    }
+    /// 最大流を流した後に呼ぶと，残余グラフで `s` から到達できる頂点が `true`
+    pub fn min_cut(&self, s: usize) -> Vec<bool> {
+        let level = self.bfs(s);
+        level.iter().map(|&l| l != usize::MAX).collect()
+    }
+    fn bfs(&self, s: usize) -> Vec<usize> {
+        let mut level = vec![usize::MAX; self.graph.len()];
+        let mut queue = std::collections::VecDeque::new();
+        level[s] = 0;
+        queue.push_back(s);
+        while let Some(v) = queue.pop_front() {
+            for e in &self.graph[v] {
+                if e.cap > 0 && level[e.to] == usize::MAX {
+                    level[e.to] = level[v] + 1;
+                    queue.push_back(e.to);
+                }
+            }
+        }
+        level
+    }
+    fn dfs(&mut self, v: usize, t: usize, up: i64, level: &[usize], iter: &mut [usize]) -> i64 {
+        if v == t {
+            return up;
+        }
+        while iter[v] < self.graph[v].len() {
+            let InnerEdge { to, rev, cap } = self.graph[v][iter[v]];
+            if cap > 0 && level[v] < level[to] {
+                let d = self.dfs(to, t, up.min(cap), level, iter);
+                if d > 0 {
+                    self.graph[v][iter[v]].cap -= d;
+                    self.graph[to][rev].cap += d;
+                    return d;
+                }
+            }
+            iter[v] += 1;
+        }
+        0
+    }
+}
@@ -0,0 +1,234 @@
+#[allow(dead_code)]
+#[path = "rollback_dsu.rs"]
+pub mod rollback_dsu;
+
+use rollback_dsu::RollbackDsu;
+
+fn main() {
+    // 区間の異なる値の個数
+    let a = vec![1, 2, 1, 3, 2, 2, 4, 1];
+    let queries = [(0, 3), (2, 6), (0, 8), (5, 5), (4, 7)];
+    let mut state = Distinct {
+        a: &a,
+        cnt: vec![0; 5],
+        distinct: 0,
+    };
+    dbg!(MoQueries::new(a.len(), &queries).run(&mut state));
+
+    // 辺 l..r だけを使ったときの連結成分の個数
+    let edges = vec![(0, 1), (2, 3), (1, 2), (0, 3), (3, 4), (4, 0)];
+    let queries = [(0, 2), (1, 4), (0, 6), (3, 3), (2, 6)];
+    let mut state = Components {
+        edges: &edges,
+        dsu: RollbackDsu::new(5),
+    };
+    dbg!(MoQueries::new(edges.len(), &queries).run_rollback(&mut state));
+}
+
+struct Distinct<'a> {
+    a: &'a [usize],
+    cnt: Vec<usize>,
+    distinct: usize,
+}
+
+impl MoState for Distinct<'_> {
+    type Output = usize;
+    fn add(&mut self, i: usize) {
+        self.cnt[self.a[i]] += 1;
+        if self.cnt[self.a[i]] == 1 {
+            self.distinct += 1;
+        }
+    }
+    fn remove(&mut self, i: usize) {
+        self.cnt[self.a[i]] -= 1;
+        if self.cnt[self.a[i]] == 0 {
+            self.distinct -= 1;
+        }
+    }
+    fn answer(&self) -> usize {
+        self.distinct
+    }
+}
+
+struct Components<'a> {
+    edges: &'a [(usize, usize)],
+    dsu: RollbackDsu,
+}
+
+impl RollbackMoState for Components<'_> {
+    type Output = usize;
+    type Snapshot = usize;
+    fn add(&mut self, i: usize) {
+        self.dsu.unite(self.edges[i].0, self.edges[i].1);
+    }
+    fn answer(&self) -> usize {
+        self.dsu.count()
+    }
+    fn snapshot(&self) -> usize {
+        self.dsu.snapshot()
+    }
+    fn rollback(&mut self, snapshot: usize) {
+        self.dsu.rollback(snapshot);
+    }
+}
+
+/// Mo's algorithm で管理する区間の状態．区間ははじめ空
+pub trait MoState {
+    type Output;
+    /// 区間に `i` を加える
+    fn add(&mut self, i: usize);
+    /// 区間から `i` を除く
+    fn remove(&mut self, i: usize);
+    /// 今の区間に対する答え
+    fn answer(&self) -> Self::Output;
+    /// 左右で操作が違うときは，以下を実装する
+    fn add_left(&mut self, i: usize) {
+        self.add(i);
+    }
+    fn add_right(&mut self, i: usize) {
+        self.add(i);
+    }
+    fn remove_left(&mut self, i: usize) {
+        self.remove(i);
+    }
+    fn remove_right(&mut self, i: usize) {
+        self.remove(i);
+    }
+}
+
+/// 要素を加えることと，状態を保存して戻すことだけができる区間の状態 (rollback 付き Mo)．区間ははじめ空
+pub trait RollbackMoState {
+    type Output;
+    type Snapshot: Clone;
+    /// 区間に `i` を加える
+    fn add(&mut self, i: usize);
+    /// 今の区間に対する答え
+    fn answer(&self) -> Self::Output;
+    /// 今の状態
+    fn snapshot(&self) -> Self::Snapshot;
+    /// `snapshot` をとったときの状態に戻す
+    fn rollback(&mut self, snapshot: Self::Snapshot);
+    /// 左右で操作が違うときは，以下を実装する
+    fn add_left(&mut self, i: usize) {
+        self.add(i);
+    }
+    fn add_right(&mut self, i: usize) {
+        self.add(i);
+    }
+}
+
+/// Hilbert 曲線上の `(x, y)` の位置．`x, y < 2^log`
+fn hilbert_order(mut x: u64, mut y: u64, log: u32) -> u64 {
+    let mut d = 0;
+    for k in (0..log).rev() {
+        let s = 1 << k;
+        let (rx, ry) = (x >> k & 1, y >> k & 1);
+        d += s * s * ((3 * rx) ^ ry);
+        // 左下と右下の象限は回転させる
+        if ry == 0 {
+            if rx == 1 {
+                (x, y) = (s - 1 - (x & (s - 1)), s - 1 - (y & (s - 1)));
+            }
+            (x, y) = (y, x);
+        }
+    }
+    d
+}
+
+/// 区間 `[l, r)` の問い合わせを，ポインタの移動が少なくなるように Hilbert 曲線の順に並べて処理する
+pub struct MoQueries {
+    n: usize,
+    queries: Vec<(usize, usize)>,
+    order: Vec<usize>,
+}
+
+impl MoQueries {
+    /// 列の長さが `n` で，問い合わせが `queries`．`l <= r <= n`
+    pub fn new(n: usize, queries: &[(usize, usize)]) -> Self {
+        let log = (usize::BITS - n.leading_zeros()).max(1);
+        let keys: Vec<u64> = queries
+            .iter()
+            .map(|&(l, r)| {
+                assert!(l <= r && r <= n);
+                hilbert_order(l as u64, r as u64, log)
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..queries.len()).collect();
+        order.sort_unstable_by_key(|&i| keys[i]);
+        Self {
+            n,
+            queries: queries.to_vec(),
+            order,
+        }
+    }
+    /// 答えを問い合わせの順に返す．`O(n √q)` 回 `add`, `remove` を呼ぶ
+    pub fn run<S: MoState>(&self, state: &mut S) -> Vec<S::Output> {
+        let mut res: Vec<Option<S::Output>> = (0..self.queries.len()).map(|_| None).collect();
+        let (mut l, mut r) = (0, 0);
+        for &i in &self.order {
+            let (ql, qr) = self.queries[i];
+            // 区間が空でなくなる順に動かす
+            while l > ql {
+                l -= 1;
+                state.add_left(l);
+            }
+            while r < qr {
+                state.add_right(r);
+                r += 1;
+            }
+            while l < ql {
+                state.remove_left(l);
+                l += 1;
+            }
+            while r > qr {
+                r -= 1;
+                state.remove_right(r);
+            }
+            res[i] = Some(state.answer());
+        }
+        res.into_iter().map(Option::unwrap).collect()
+    }
+    /// 答えを問い合わせの順に返す．`O(n √q)` 回 `add` を呼び，`O(q)` 回 `snapshot`, `rollback` を呼ぶ
+    ///
+    /// 左端を幅 `n / √q` のブロックに分け，ブロックごとに右端の昇順に処理する．
+    pub fn run_rollback<S: RollbackMoState>(&self, state: &mut S) -> Vec<S::Output> {
+        let q = self.queries.len();
+        let width = ((self.n as f64 / (q as f64).sqrt()) as usize).max(1);
+        let mut order: Vec<usize> = (0..q).collect();
+        order.sort_unstable_by_key(|&i| (self.queries[i].0 / width, self.queries[i].1));
+        let mut res: Vec<Option<S::Output>> = (0..q).map(|_| None).collect();
+        let empty = state.snapshot();
+        let mut order = order.into_iter().peekable();
+        while let Some(&first) = order.peek() {
+            let block = self.queries[first].0 / width;
+            // 区間 [border, r) を持っておき，右端だけ伸ばす
+            let border = (block + 1) * width;
+            let mut r = border;
+            state.rollback(empty.clone());
+            while let Some(i) = order.next_if(|&i| self.queries[i].0 / width == block) {
+                let (ql, qr) = self.queries[i];
+                if qr <= border {
+                    // ブロックをまたがないものは直接．右端の昇順なので，このとき区間は空
+                    let s = state.snapshot();
+                    for j in ql..qr {
+                        state.add_right(j);
+                    }
+                    res[i] = Some(state.answer());
+                    state.rollback(s);
+                    continue;
+                }
+                while r < qr {
+                    state.add_right(r);
+                    r += 1;
+                }
+                let s = state.snapshot();
+                for j in (ql..border).rev() {
+                    state.add_left(j);
+                }
+                res[i] = Some(state.answer());
+                state.rollback(s);
+            }
+        }
+        res.into_iter().map(Option::unwrap).collect()
+    }
+}
@@ -0,0 +1,241 @@
+use std::{
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+fn main() {
+    type Mint = ModInt998244353;
+    let a = Mint::new(3);
+    let b = Mint::from(-1);
+    dbg!(a + b, a - b, a * b, a / b, -a);
+    dbg!(a.pow(1_000_000), a.inv() * a);
+    dbg!((1..=10).map(Mint::new).product::<Mint>());
+    dbg!([a, b, a].iter().sum::<Mint>());
+    dbg!("123456789012345678901234567890".parse::<Mint>().unwrap());
+    dbg!(ModInt1000000007::from(10u64.pow(18)));
+    // 素数でなくても，互いに素なら逆元がある
+    dbg!(ModInt::<10>::new(3).inv());
+}
+
+pub type ModInt998244353 = ModInt<998244353>;
+pub type ModInt1000000007 = ModInt<1000000007>;
+
+/// `M` を法とする整数．`M` は `1` 以上 `2^31` 未満
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u32> {
+    val: u32,
+}
+
+impl<const M: u32> ModInt<M> {
+    pub const fn modulus() -> u32 {
+        M
+    }
+    pub fn new(val: u32) -> Self {
+        Self { val: val % M }
+    }
+    /// `val < M` でなければならない
+    pub const fn raw(val: u32) -> Self {
+        Self { val }
+    }
+    pub const fn val(self) -> u32 {
+        self.val
+    }
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut res = Self::raw(1 % M);
+        let mut base = self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        res
+    }
+    /// 逆元．`M` と互いに素でなければ panic
+    pub fn inv(self) -> Self {
+        // 拡張 Euclid の互除法
+        let (mut a, mut b) = (self.val as i64, M as i64);
+        let (mut x, mut y) = (1, 0);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        assert_eq!(a, 1, "{} has no inverse modulo {}", self.val, M);
+        Self::raw(x.rem_euclid(M as i64) as u32)
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl<const M: u32> From<$t> for ModInt<M> {
+            fn from(x: $t) -> Self {
+                Self::raw((x % M as $t) as u32)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {$(
+        impl<const M: u32> From<$t> for ModInt<M> {
+            fn from(x: $t) -> Self {
+                Self::raw((x as i128).rem_euclid(M as i128) as u32)
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u64, usize, u128);
+impl_from_signed!(i8, i16, i32, i64, isize, i128);
+
+impl<const M: u32> From<u8> for ModInt<M> {
+    fn from(x: u8) -> Self {
+        Self::new(x as u32)
+    }
+}
+
+impl<const M: u32> From<u16> for ModInt<M> {
+    fn from(x: u16) -> Self {
+        Self::new(x as u32)
+    }
+}
+
+impl<const M: u32> From<u32> for ModInt<M> {
+    fn from(x: u32) -> Self {
+        Self::new(x)
+    }
+}
+
+impl<const M: u32> FromStr for ModInt<M> {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // 桁数が多くても読めるように，1桁ずつ
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            // 標準ライブラリと同じエラーを返す
+            return Err(s.parse::<u32>().unwrap_err());
+        }
+        let res = digits.bytes().fold(Self::raw(0), |acc, c| {
+            acc * Self::raw(10 % M) + Self::new((c - b'0') as u32)
+        });
+        Ok(if neg { -res } else { res })
+    }
+}
+
+impl<const M: u32> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.val.fmt(f)
+    }
+}
+
+impl<const M: u32> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.val.fmt(f)
+    }
+}
+
+impl<const M: u32> Neg for ModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::raw(if self.val == 0 { 0 } else { M - self.val })
+    }
+}
+
+impl<const M: u32> AddAssign for ModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.val += rhs.val;
+        if self.val >= M {
+            self.val -= M;
+        }
+    }
+}
+
+impl<const M: u32> SubAssign for ModInt<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.val < rhs.val {
+            self.val += M;
+        }
+        self.val -= rhs.val;
+    }
+}
+
+impl<const M: u32> MulAssign for ModInt<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.val = (self.val as u64 * rhs.val as u64 % M as u64) as u32;
+    }
+}
+
+impl<const M: u32> DivAssign for ModInt<M> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inv();
+    }
+}
+
+macro_rules! impl_binop {
+    ($($trait:ident, $method:ident, $assign:ident;)*) => {$(
+        impl<const M: u32> $trait for ModInt<M> {
+            type Output = Self;
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assign(rhs);
+                self
+            }
+        }
+        impl<const M: u32> $trait<&ModInt<M>> for ModInt<M> {
+            type Output = Self;
+            fn $method(self, rhs: &Self) -> Self {
+                self.$method(*rhs)
+            }
+        }
+        impl<const M: u32> $trait<ModInt<M>> for &ModInt<M> {
+            type Output = ModInt<M>;
+            fn $method(self, rhs: ModInt<M>) -> ModInt<M> {
+                (*self).$method(rhs)
+            }
+        }
+        impl<const M: u32> $trait<&ModInt<M>> for &ModInt<M> {
+            type Output = ModInt<M>;
+            fn $method(self, rhs: &ModInt<M>) -> ModInt<M> {
+                (*self).$method(*rhs)
+            }
+        }
+    )*};
+}
+
+impl_binop! {
+    Add, add, add_assign;
+    Sub, sub, sub_assign;
+    Mul, mul, mul_assign;
+    Div, div, div_assign;
+}
+
+impl<const M: u32> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::raw(0), |a, b| a + b)
+    }
+}
+
+impl<'a, const M: u32> Sum<&'a Self> for ModInt<M> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const M: u32> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::raw(1 % M), |a, b| a * b)
+    }
+}
+
+impl<'a, const M: u32> Product<&'a Self> for ModInt<M> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
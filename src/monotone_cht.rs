@@ -0,0 +1,96 @@
+fn main() {
+    let mut cht = MonotoneCht::new();
+    for (a, b) in [(3, 0), (1, 2), (0, 5), (-1, 10)] {
+        cht.add_line(a, b);
+    }
+    dbg!(cht.query_any(-2), cht.query_any(100));
+    dbg!((0..8).map(|x| cht.query(x)).collect::<Vec<_>>());
+
+    // dp[i] = min_{j < i} (dp[j] + (x[i] - x[j])^2 + c)
+    let (xs, c) = ([0i64, 2, 3, 7, 8, 12], 5);
+    let mut dp = vec![0; xs.len()];
+    let mut cht = MonotoneCht::new();
+    cht.add_line(-2 * xs[0], xs[0] * xs[0]);
+    for i in 1..xs.len() {
+        dp[i] = cht.query(xs[i]) + xs[i] * xs[i] + c;
+        cht.add_line(-2 * xs[i], dp[i] + xs[i] * xs[i]);
+    }
+    dbg!(dp);
+}
+
+/// 直線 `ax + b` の最小値．追加する直線の傾きが単調非増加で，`query` の `x` が単調非減少なら償却 `O(1)`
+///
+/// 最大値が欲しければ，`-a`, `-b` を追加して符号を反転する．
+#[derive(Clone, Default, Debug)]
+pub struct MonotoneCht {
+    lines: Vec<(i64, i64)>,
+    /// `query` で使う先頭．これより前の直線はもう最小にならない
+    head: usize,
+}
+
+impl MonotoneCht {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.head == self.lines.len()
+    }
+    /// `l2` が `l1` と `l3` (傾きは `l1 > l2 > l3`) があれば不要か
+    fn unnecessary(l1: (i64, i64), l2: (i64, i64), l3: (i64, i64)) -> bool {
+        // l1 と l3 の交点が，l1 と l2 の交点より左
+        let (a1, b1) = (l1.0 as i128, l1.1 as i128);
+        let (a2, b2) = (l2.0 as i128, l2.1 as i128);
+        let (a3, b3) = (l3.0 as i128, l3.1 as i128);
+        (b3 - b1) * (a1 - a2) <= (b2 - b1) * (a1 - a3)
+    }
+    /// 傾き `a` はこれまでに追加したもの以下
+    pub fn add_line(&mut self, a: i64, b: i64) {
+        if let Some(&(la, lb)) = self.lines[self.head..].last() {
+            assert!(a <= la, "slopes must be non-increasing");
+            if a == la {
+                if b >= lb {
+                    return;
+                }
+                self.lines.pop();
+            }
+        }
+        while self.lines.len() - self.head >= 2
+            && Self::unnecessary(
+                self.lines[self.lines.len() - 2],
+                self.lines[self.lines.len() - 1],
+                (a, b),
+            )
+        {
+            self.lines.pop();
+        }
+        self.lines.push((a, b));
+    }
+    fn eval((a, b): (i64, i64), x: i64) -> i64 {
+        a * x + b
+    }
+    /// `x` での最小値．`x` はこれまでの `query` の `x` 以上．直線がなければ panic
+    pub fn query(&mut self, x: i64) -> i64 {
+        assert!(!self.is_empty());
+        while self.head + 1 < self.lines.len()
+            && Self::eval(self.lines[self.head + 1], x) <= Self::eval(self.lines[self.head], x)
+        {
+            self.head += 1;
+        }
+        Self::eval(self.lines[self.head], x)
+    }
+    /// 任意の `x` での最小値．二分探索で `O(log n)`．`query` で使わなくなった直線は使わない
+    pub fn query_any(&self, x: i64) -> i64 {
+        assert!(!self.is_empty());
+        let (mut lo, mut hi) = (self.head, self.lines.len() - 1);
+        // 最小となる直線は，隣の直線より値が小さい最初のもの
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if Self::eval(self.lines[mid + 1], x) <= Self::eval(self.lines[mid], x) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Self::eval(self.lines[lo], x)
+    }
+}
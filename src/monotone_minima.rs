@@ -0,0 +1,162 @@
+fn main() {
+    // (x_i - y_j)^2 (x, y は昇順) は Monge
+    let (xs, ys) = ([1i64, 4, 5, 9, 12], [0i64, 3, 6, 7, 10, 11]);
+    let f = |i: usize, j: usize| (xs[i] - ys[j]).pow(2);
+    dbg!(
+        monotone_minima(xs.len(), ys.len(), f),
+        smawk(xs.len(), ys.len(), f)
+    );
+
+    // 0 → 5 の最短路．i → j の辺の重みは (j - i)^2 + 10
+    dbg!(monge_shortest_path(5, |i, j| ((j - i) * (j - i)) as i64 + 10));
+}
+
+/// 各行の最小値をとる列 (複数あれば最も左) を返す．`O((h + w) log h)` 回 `f` を呼ぶ
+///
+/// 最小値をとる列が行について単調非減少 (monotone) であること．Monge なら満たす．
+pub fn monotone_minima<T: PartialOrd>(
+    h: usize,
+    w: usize,
+    mut f: impl FnMut(usize, usize) -> T,
+) -> Vec<usize> {
+    assert!(h == 0 || w >= 1);
+    fn rec<T: PartialOrd>(
+        rows: std::ops::Range<usize>,
+        lo: usize,
+        hi: usize,
+        f: &mut impl FnMut(usize, usize) -> T,
+        res: &mut [usize],
+    ) {
+        if rows.is_empty() {
+            return;
+        }
+        let m = (rows.start + rows.end) / 2;
+        let (mut best, mut min) = (lo, f(m, lo));
+        for j in lo + 1..=hi {
+            let v = f(m, j);
+            if v < min {
+                (best, min) = (j, v);
+            }
+        }
+        res[m] = best;
+        rec(rows.start..m, lo, best, f, res);
+        rec(m + 1..rows.end, best, hi, f, res);
+    }
+    let mut res = vec![0; h];
+    if h > 0 {
+        rec(0..h, 0, w - 1, &mut f, &mut res);
+    }
+    res
+}
+
+/// 各行の最小値をとる列 (複数あれば最も左) を返す．`O(h + w)` 回 `f` を呼ぶ
+///
+/// 行列が totally monotone であること．Monge なら満たす．
+pub fn smawk<T: PartialOrd>(
+    h: usize,
+    w: usize,
+    mut f: impl FnMut(usize, usize) -> T,
+) -> Vec<usize> {
+    assert!(h == 0 || w >= 1);
+    fn rec<T: PartialOrd>(
+        rows: &[usize],
+        cols: &[usize],
+        f: &mut impl FnMut(usize, usize) -> T,
+        res: &mut [usize],
+    ) {
+        if rows.is_empty() {
+            return;
+        }
+        // REDUCE: 最小値になりえない列を除いて，列を行の数以下にする
+        let mut st: Vec<usize> = vec![];
+        for &c in cols {
+            while let Some(&last) = st.last() {
+                let r = rows[st.len() - 1];
+                if f(r, c) < f(r, last) {
+                    st.pop();
+                } else {
+                    break;
+                }
+            }
+            if st.len() < rows.len() {
+                st.push(c);
+            }
+        }
+        // 奇数番目の行を再帰的に解く
+        let odd: Vec<usize> = rows.iter().skip(1).step_by(2).copied().collect();
+        rec(&odd, &st, f, res);
+        // 偶数番目の行の答えは，前後の奇数番目の行の答えの間にある
+        let mut j = 0;
+        for i in (0..rows.len()).step_by(2) {
+            let r = rows[i];
+            let end = if i + 1 < rows.len() {
+                res[rows[i + 1]]
+            } else {
+                *st.last().unwrap()
+            };
+            let (mut best, mut min) = (st[j], f(r, st[j]));
+            while st[j] != end {
+                j += 1;
+                let v = f(r, st[j]);
+                if v < min {
+                    (best, min) = (st[j], v);
+                }
+            }
+            res[r] = best;
+        }
+    }
+    let mut res = vec![0; h];
+    let rows: Vec<usize> = (0..h).collect();
+    let cols: Vec<usize> = (0..w).collect();
+    rec(&rows, &cols, &mut f, &mut res);
+    res
+}
+
+/// 頂点 `0..=n`，辺 `i → j` (`i < j`) の重みが `f(i, j)` の DAG で，`0` から各頂点への最短路長．`O(n log n)` 回 `f` を呼ぶ
+///
+/// `f` は Monge であること．`f(i, j)` は `0` から `i` への最短路長が確定してから呼ぶので，
+/// `dp[j] = min_{i < j} (dp[i] + f(i, j))` で `f` が `dp` に依存してよい (簡易版 LARSCH)．
+pub fn monge_shortest_path(n: usize, mut f: impl FnMut(usize, usize) -> i64) -> Vec<i64> {
+    fn check(
+        i: usize,
+        k: usize,
+        f: &mut impl FnMut(usize, usize) -> i64,
+        dp: &mut [i64],
+        amin: &mut [usize],
+    ) {
+        let v = dp[k] + f(k, i);
+        if v < dp[i] {
+            dp[i] = v;
+            amin[i] = k;
+        }
+    }
+    // l, r の暫定の最適な遷移元が求まっているとき，(l, r) を確定させる
+    fn rec(
+        l: usize,
+        r: usize,
+        f: &mut impl FnMut(usize, usize) -> i64,
+        dp: &mut [i64],
+        amin: &mut [usize],
+    ) {
+        if r - l <= 1 {
+            return;
+        }
+        let m = (l + r) / 2;
+        for k in amin[l]..=amin[r] {
+            check(m, k, f, dp, amin);
+        }
+        rec(l, m, f, dp, amin);
+        for k in l + 1..=m {
+            check(r, k, f, dp, amin);
+        }
+        rec(m, r, f, dp, amin);
+    }
+    let mut dp = vec![i64::MAX; n + 1];
+    let mut amin = vec![0; n + 1];
+    dp[0] = 0;
+    if n > 0 {
+        check(n, 0, &mut f, &mut dp, &mut amin);
+        rec(0, n, &mut f, &mut dp, &mut amin);
+    }
+    dp
+}
@@ -0,0 +1,240 @@
+use std::{
+    cell::Cell,
+    fmt,
+    iter::Product,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    time::Instant,
+};
+
+fn main() {
+    let n = (1 << 61) - 1;
+    let mont = Montgomery64::new(n);
+    dbg!(mont.mul_mod(n - 1, n - 1), mont.pow_mod(3, n - 1));
+    dbg!(mont.prod_mod(&[2, 3, 5, 7, 11]));
+
+    // u128 の % との比較
+    let (a, k) = (123_456_789_123_456_789, 10_000_000);
+    let start = Instant::now();
+    let mut x = 1u64;
+    for _ in 0..k {
+        x = (x as u128 * a as u128 % n as u128) as u64;
+    }
+    dbg!(x, start.elapsed());
+    let start = Instant::now();
+    let (a, mut x) = (mont.to_mont(a), mont.to_mont(1));
+    for _ in 0..k {
+        x = mont.mul(x, a);
+    }
+    dbg!(mont.from_mont(x), start.elapsed());
+
+    // 同じことを MontModInt64 で
+    MontModInt64::set_modulus(n);
+    let start = Instant::now();
+    let (a, mut x) = (
+        MontModInt64::new(123_456_789_123_456_789),
+        MontModInt64::new(1),
+    );
+    for _ in 0..k {
+        x *= a;
+    }
+    dbg!(x, start.elapsed());
+    dbg!(MontModInt64::new(3).pow(n - 1), -MontModInt64::new(1));
+}
+
+/// 奇数 `n` を法とする Montgomery 乗算．値 `a` を `a * 2^64 mod n` (Montgomery 表現) で持つ
+#[derive(Clone, Copy, Debug)]
+pub struct Montgomery64 {
+    n: u64,
+    // n * n_inv ≡ 1 (mod 2^64)
+    n_inv: u64,
+    // 2^128 mod n
+    r2: u64,
+}
+
+impl Montgomery64 {
+    /// `n` は奇数
+    pub const fn new(n: u64) -> Self {
+        assert!(n & 1 == 1, "modulus must be odd");
+        // Newton 法．1回ごとに正しいビット数が倍になる
+        let mut n_inv = n;
+        let mut i = 0;
+        while i < 5 {
+            n_inv = n_inv.wrapping_mul(2u64.wrapping_sub(n.wrapping_mul(n_inv)));
+            i += 1;
+        }
+        let r = (1u128 << 64) % n as u128;
+        let r2 = (r * r % n as u128) as u64;
+        Self { n, n_inv, r2 }
+    }
+    pub const fn modulus(&self) -> u64 {
+        self.n
+    }
+    /// `t * 2^-64 mod n`．`t < n * 2^64` でなければならない
+    pub fn reduce(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.n_inv);
+        let mn = m as u128 * self.n as u128;
+        // t と mn の下位 64 bit は等しい
+        let (hi, borrow) = ((t >> 64) as u64).overflowing_sub((mn >> 64) as u64);
+        if borrow {
+            hi.wrapping_add(self.n)
+        } else {
+            hi
+        }
+    }
+    /// 通常の値 (`a < n`) から Montgomery 表現へ
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_mont(&self, a: u64) -> u64 {
+        self.reduce(a as u128 * self.r2 as u128)
+    }
+    /// Montgomery 表現から通常の値へ
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_mont(&self, a: u64) -> u64 {
+        self.reduce(a as u128)
+    }
+    /// 以下 `a`, `b` は Montgomery 表現
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        let (s, overflow) = a.overflowing_add(b);
+        if overflow || s >= self.n {
+            s.wrapping_sub(self.n)
+        } else {
+            s
+        }
+    }
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        let (d, borrow) = a.overflowing_sub(b);
+        if borrow {
+            d.wrapping_add(self.n)
+        } else {
+            d
+        }
+    }
+    pub fn pow(&self, mut a: u64, mut exp: u64) -> u64 {
+        let mut res = self.to_mont(1 % self.n);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res = self.mul(res, a);
+            }
+            a = self.mul(a, a);
+            exp >>= 1;
+        }
+        res
+    }
+    /// 以下は通常の値 (`< n`) を受け取って通常の値を返す
+    pub fn mul_mod(&self, a: u64, b: u64) -> u64 {
+        // (a * 2^64) * b * 2^-64 = a * b
+        self.mul(self.to_mont(a), b)
+    }
+    pub fn pow_mod(&self, a: u64, exp: u64) -> u64 {
+        self.from_mont(self.pow(self.to_mont(a), exp))
+    }
+    pub fn prod_mod(&self, a: &[u64]) -> u64 {
+        let res = a.iter().fold(self.to_mont(1 % self.n), |acc, &x| {
+            self.mul(acc, self.to_mont(x))
+        });
+        self.from_mont(res)
+    }
+}
+
+thread_local! {
+    static MONT: Cell<Montgomery64> = const { Cell::new(Montgomery64::new((1 << 61) - 1)) };
+}
+
+/// 法を実行時に決める `u64` の範囲の整数．値は Montgomery 表現で持つので，掛け算を続けても変換しない
+///
+/// 法はスレッドごとに1つの奇数で，初期値は `2^61 - 1`．
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MontModInt64 {
+    // Montgomery 表現
+    mont: u64,
+}
+
+impl MontModInt64 {
+    /// 法を変更する．それまでに作った値は意味を失う．
+    pub fn set_modulus(n: u64) {
+        MONT.with(|m| m.set(Montgomery64::new(n)));
+    }
+    fn montgomery() -> Montgomery64 {
+        MONT.with(|m| m.get())
+    }
+    pub fn modulus() -> u64 {
+        Self::montgomery().modulus()
+    }
+    pub fn new(val: u64) -> Self {
+        let mont = Self::montgomery();
+        Self {
+            mont: mont.to_mont(val % mont.modulus()),
+        }
+    }
+    pub fn val(self) -> u64 {
+        Self::montgomery().from_mont(self.mont)
+    }
+    pub fn pow(self, exp: u64) -> Self {
+        Self {
+            mont: Self::montgomery().pow(self.mont, exp),
+        }
+    }
+}
+
+impl fmt::Display for MontModInt64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.val().fmt(f)
+    }
+}
+
+impl fmt::Debug for MontModInt64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.val().fmt(f)
+    }
+}
+
+impl Neg for MontModInt64 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::default() - self
+    }
+}
+
+impl AddAssign for MontModInt64 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.mont = Self::montgomery().add(self.mont, rhs.mont);
+    }
+}
+
+impl SubAssign for MontModInt64 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.mont = Self::montgomery().sub(self.mont, rhs.mont);
+    }
+}
+
+impl MulAssign for MontModInt64 {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.mont = Self::montgomery().mul(self.mont, rhs.mont);
+    }
+}
+
+macro_rules! impl_binop {
+    ($($trait:ident, $method:ident, $assign:ident;)*) => {$(
+        impl $trait for MontModInt64 {
+            type Output = Self;
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assign(rhs);
+                self
+            }
+        }
+    )*};
+}
+
+impl_binop! {
+    Add, add, add_assign;
+    Sub, sub, sub_assign;
+    Mul, mul, mul_assign;
+}
+
+impl Product for MontModInt64 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), |acc, x| acc * x)
+    }
+}
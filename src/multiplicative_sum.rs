@@ -0,0 +1,104 @@
+#[allow(dead_code)]
+#[path = "prime_count.rs"]
+pub mod prime_count;
+
+#[allow(dead_code)]
+#[path = "modint.rs"]
+pub mod modint;
+
+use prime_count::PrimeSumTable;
+use std::ops::{Add, Mul, Sub};
+
+fn main() {
+    type Mint = modint::ModInt998244353;
+    let n = 10u64.pow(10);
+    // Σ φ(i): φ(p) = p - 1, φ(p^e) = p^{e - 1} (p - 1)
+    let count = PrimeSumTable::new(n, |v| Mint::from(v.saturating_sub(1)), |_| Mint::new(1));
+    let sum = PrimeSumTable::new(
+        n,
+        |v| Mint::from((v as u128 * (v as u128 + 1) / 2).saturating_sub(1)),
+        Mint::from,
+    );
+    dbg!(multiplicative_sum(
+        n,
+        count.primes(),
+        |v| sum.get(v) - count.get(v),
+        |p, e| Mint::from(p).pow(e as u64 - 1) * Mint::from(p - 1),
+    ));
+
+    // Σ μ(i) (Mertens 関数) と Σ d(i) (約数の個数)．小さい n で確かめる
+    let n = 1000;
+    let count = PrimeSumTable::new(n, |v| v.saturating_sub(1) as i64, |_| 1);
+    dbg!(multiplicative_sum(
+        n,
+        count.primes(),
+        |v| -count.get(v),
+        |_, e| if e == 1 { -1 } else { 0 }
+    ));
+    dbg!(multiplicative_sum(
+        n,
+        count.primes(),
+        |v| 2 * count.get(v),
+        |_, e| e as i64 + 1
+    ));
+}
+
+/// min_25 篩．乗法的関数 `f` について `Σ_{i=1}^{n} f(i)` を求める
+///
+/// - `primes`: `sqrt(n)` 以下の素数の昇順 (`PrimeSumTable::primes` など)
+/// - `prime_sum(v)`: `Σ_{p <= v, p は素数} f(p)`．`v` は `floor(n / i)` の形か `sqrt(n)` 以下
+/// - `f_pe(p, e)`: `f(p^e)` (`e >= 1`)
+pub fn multiplicative_sum<T>(
+    n: u64,
+    primes: &[u64],
+    prime_sum: impl Fn(u64) -> T,
+    f_pe: impl Fn(u64, u32) -> T,
+) -> T
+where
+    T: Copy + From<u8> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    if n == 0 {
+        return T::from(0);
+    }
+    // before[j]: primes[j] より小さい素数 p についての Σ f(p)
+    let sq = n.isqrt();
+    let before: Vec<T> = primes
+        .iter()
+        .map(|&p| p - 1)
+        .chain([sq])
+        .map(&prime_sum)
+        .collect();
+    T::from(1) + rec(n, 0, primes, &before, &prime_sum, &f_pe)
+}
+
+/// `2 <= i <= v` で最小素因数が `primes[j]` 以上の `i` についての `Σ f(i)`
+fn rec<T>(
+    v: u64,
+    j: usize,
+    primes: &[u64],
+    before: &[T],
+    prime_sum: &impl Fn(u64) -> T,
+    f_pe: &impl Fn(u64, u32) -> T,
+) -> T
+where
+    T: Copy + From<u8> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    // 素数
+    let mut res = prime_sum(v) - before[j];
+    // 合成数 (最小素因数 p，その指数 e で分ける)
+    for (k, &p) in primes.iter().enumerate().skip(j) {
+        if p * p > v {
+            break;
+        }
+        let (mut pe, mut e) = (p, 1);
+        while pe * p <= v {
+            // p^e × (最小素因数が p より大きい数) と p^{e + 1}
+            res = res
+                + f_pe(p, e) * rec(v / pe, k + 1, primes, before, prime_sum, f_pe)
+                + f_pe(p, e + 1);
+            pe *= p;
+            e += 1;
+        }
+    }
+    res
+}
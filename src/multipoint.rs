@@ -0,0 +1,99 @@
+#[allow(dead_code)]
+#[path = "fps.rs"]
+pub mod fps;
+
+use fps::{convolution::modint::ModInt, Fps};
+use std::ops::Range;
+
+fn main() {
+    type Mint = ModInt<998244353>;
+    let f = Fps::<998244353>::from_u32(&[1, 2, 3]); // 1 + 2x + 3x^2
+    let xs: Vec<Mint> = (0..5).map(Mint::new).collect();
+    let ys = multipoint_eval(&f, &xs);
+    dbg!(&ys);
+    dbg!(interpolate(&xs[..3], &ys[..3]));
+    dbg!(interpolate(&xs, &ys));
+
+    let n = 1 << 17;
+    let f = Fps::<998244353>::new((0..n).map(|i| Mint::new(i as u32)).collect());
+    let xs: Vec<Mint> = (0..n).map(|i| Mint::new(i as u32 * 7 + 1)).collect();
+    let ys = multipoint_eval(&f, &xs);
+    dbg!(ys[12345], f.eval(xs[12345]));
+    dbg!(interpolate(&xs, &ys) == f);
+}
+
+/// 葉が `x - xs[i]` (足りない分は `1`) の部分積の木．`tree[1]` が根
+fn subproduct_tree<const M: u32>(xs: &[ModInt<M>]) -> Vec<Fps<M>> {
+    let sz = xs.len().next_power_of_two();
+    let mut tree = vec![Fps::from_u32(&[1]); 2 * sz];
+    for (i, &x) in xs.iter().enumerate() {
+        tree[sz + i] = Fps::new(vec![-x, ModInt::new(1)]);
+    }
+    for i in (1..sz).rev() {
+        tree[i] = &tree[2 * i] * &tree[2 * i + 1];
+    }
+    tree
+}
+
+/// 各 `xs[i]` での `f` の値．`O(n log^2 n)`
+pub fn multipoint_eval<const M: u32>(f: &Fps<M>, xs: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    if xs.is_empty() {
+        return vec![];
+    }
+    let tree = subproduct_tree(xs);
+    let mut res = vec![ModInt::raw(0); xs.len()];
+    eval_rec(&tree, xs, 1, 0..tree.len() / 2, f % &tree[1], &mut res);
+    res
+}
+
+/// `f` は `f mod tree[v]`，`range` はノード `v` が担当する点の区間
+fn eval_rec<const M: u32>(
+    tree: &[Fps<M>],
+    xs: &[ModInt<M>],
+    v: usize,
+    range: Range<usize>,
+    f: Fps<M>,
+    res: &mut [ModInt<M>],
+) {
+    if range.start >= xs.len() {
+        return;
+    }
+    // 次数が小さければ Horner 法で直接求めたほうが速い
+    if f.len() <= 32 {
+        for i in range.start..range.end.min(xs.len()) {
+            res[i] = f.eval(xs[i]);
+        }
+        return;
+    }
+    let mid = (range.start + range.end) / 2;
+    eval_rec(tree, xs, 2 * v, range.start..mid, &f % &tree[2 * v], res);
+    eval_rec(
+        tree,
+        xs,
+        2 * v + 1,
+        mid..range.end,
+        &f % &tree[2 * v + 1],
+        res,
+    );
+}
+
+/// `f(xs[i]) = ys[i]` となる次数 `n - 1` 以下の `f`．`xs` は相異なる．`O(n log^2 n)`
+pub fn interpolate<const M: u32>(xs: &[ModInt<M>], ys: &[ModInt<M>]) -> Fps<M> {
+    assert_eq!(xs.len(), ys.len());
+    let n = xs.len();
+    if n == 0 {
+        return Fps::new(vec![]);
+    }
+    let tree = subproduct_tree(xs);
+    let sz = tree.len() / 2;
+    // f = Σ ys[i] / P'(xs[i]) Π_{j ≠ i} (x - xs[j])，P = Π (x - xs[j])
+    let w = multipoint_eval(&tree[1].derivative(), xs);
+    let mut g = vec![Fps::new(vec![]); 2 * sz];
+    for i in 0..n {
+        g[sz + i] = Fps::new(vec![ys[i] / w[i]]);
+    }
+    for v in (1..sz).rev() {
+        g[v] = &(&g[2 * v] * &tree[2 * v + 1]) + &(&g[2 * v + 1] * &tree[2 * v]);
+    }
+    g.swap_remove(1).prefix(n)
+}
@@ -0,0 +1,207 @@
+use std::fmt::{self, Write as _};
+use std::io::{self, StdoutLock, Write};
+
+fn main() {
+    let mut out = Output::stdout();
+    out.writeln(-1234567890123i64);
+    out.write(u128::MAX).write(' ').writeln("abc");
+    out.writeln_iter([3, 1, 4, 1, 5], " ");
+    out.writeln_iter(["a", "b"], "\n");
+    writeln!(out, "{:.3}", std::f64::consts::PI).unwrap();
+}
+
+/// まとめて書き出す出力．drop するときに flush する
+pub struct Output<W: Write> {
+    w: W,
+    buf: Vec<u8>,
+}
+
+impl Output<StdoutLock<'static>> {
+    /// 標準出力に
+    pub fn stdout() -> Self {
+        Self::new(io::stdout().lock())
+    }
+}
+
+impl<W: Write> Output<W> {
+    const CAPACITY: usize = 1 << 16;
+    pub fn new(w: W) -> Self {
+        Self {
+            w,
+            buf: Vec::with_capacity(Self::CAPACITY),
+        }
+    }
+    fn bytes(&mut self, s: &[u8]) {
+        self.buf.extend_from_slice(s);
+        if self.buf.len() >= Self::CAPACITY {
+            self.flush();
+        }
+    }
+    pub fn write<T: Writable>(&mut self, x: T) -> &mut Self {
+        x.write_to(&mut self.buf);
+        if self.buf.len() >= Self::CAPACITY {
+            self.flush();
+        }
+        self
+    }
+    /// `x` と改行
+    pub fn writeln<T: Writable>(&mut self, x: T) -> &mut Self {
+        self.write(x).write('\n')
+    }
+    /// `iter` の要素を `sep` 区切りで書いて改行．空なら改行だけ
+    pub fn writeln_iter<I>(&mut self, iter: I, sep: &str) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: Writable,
+    {
+        for (i, x) in iter.into_iter().enumerate() {
+            if i > 0 {
+                self.bytes(sep.as_bytes());
+            }
+            self.write(x);
+        }
+        self.write('\n')
+    }
+    pub fn flush(&mut self) {
+        self.w.write_all(&self.buf).unwrap();
+        self.w.flush().unwrap();
+        self.buf.clear();
+    }
+}
+
+impl<W: Write> Drop for Output<W> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// `write!(out, ...)` で書式つきで書けるように
+impl<W: Write> fmt::Write for Output<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.bytes(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// `Output` に書けるもの
+pub trait Writable {
+    fn write_to(&self, buf: &mut Vec<u8>);
+}
+
+impl<T: Writable + ?Sized> Writable for &T {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        (**self).write_to(buf);
+    }
+}
+
+impl Writable for str {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Writable for String {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl Writable for char {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.encode_utf8(&mut [0; 4]).as_bytes());
+    }
+}
+
+/// `"00"` から `"99"` まで
+const DIGITS2: &[u8; 200] = b"\
+0001020304050607080910111213141516171819\
+2021222324252627282930313233343536373839\
+4041424344454647484950515253545556575859\
+6061626364656667686970717273747576777879\
+8081828384858687888990919293949596979899";
+
+/// 符号なし整数を 10 進で書く関数．下の桁から 2 桁ずつ
+macro_rules! def_write_unsigned {
+    ($name:ident, $t:ty, $len:expr) => {
+        fn $name(mut x: $t, buf: &mut Vec<u8>) {
+            let mut tmp = [0; $len];
+            let mut i = tmp.len();
+            while x >= 100 {
+                let d = (x % 100) as usize * 2;
+                x /= 100;
+                i -= 2;
+                tmp[i..i + 2].copy_from_slice(&DIGITS2[d..d + 2]);
+            }
+            if x >= 10 {
+                let d = x as usize * 2;
+                i -= 2;
+                tmp[i..i + 2].copy_from_slice(&DIGITS2[d..d + 2]);
+            } else {
+                i -= 1;
+                tmp[i] = b'0' + x as u8;
+            }
+            buf.extend_from_slice(&tmp[i..]);
+        }
+    };
+}
+
+def_write_unsigned!(write_u64, u64, 20);
+// u128 の割り算は遅いので，u64 に収まるものは write_u64 で
+def_write_unsigned!(write_u128, u128, 39);
+
+macro_rules! impl_writable_unsigned {
+    ($($t:ty)*) => {$(
+        impl Writable for $t {
+            fn write_to(&self, buf: &mut Vec<u8>) {
+                write_u64(*self as u64, buf);
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_writable_signed {
+    ($($t:ty)*) => {$(
+        impl Writable for $t {
+            fn write_to(&self, buf: &mut Vec<u8>) {
+                if *self < 0 {
+                    buf.push(b'-');
+                }
+                write_u64(self.unsigned_abs() as u64, buf);
+            }
+        }
+    )*};
+}
+
+impl_writable_unsigned!(u8 u16 u32 u64 usize);
+impl_writable_signed!(i8 i16 i32 i64 isize);
+
+impl Writable for u128 {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        if let Ok(x) = u64::try_from(*self) {
+            write_u64(x, buf);
+        } else {
+            write_u128(*self, buf);
+        }
+    }
+}
+
+impl Writable for i128 {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        if *self < 0 {
+            buf.push(b'-');
+        }
+        self.unsigned_abs().write_to(buf);
+    }
+}
+
+macro_rules! impl_writable_display {
+    ($($t:ty)*) => {$(
+        impl Writable for $t {
+            fn write_to(&self, buf: &mut Vec<u8>) {
+                write!(buf, "{}", self).unwrap();
+            }
+        }
+    )*};
+}
+
+impl_writable_display!(f32 f64 bool);
@@ -0,0 +1,89 @@
+#[allow(dead_code)]
+#[path = "rollback_dsu.rs"]
+pub mod rollback_dsu;
+
+use rollback_dsu::RollbackDsu;
+
+fn main() {
+    // 何本目までの辺を加えると u と v が連結になるか
+    let edges = vec![(0, 1), (2, 3), (1, 2), (3, 4)];
+    let queries = vec![(0, 3), (0, 4), (1, 1), (2, 3), (0, 5)];
+    let mut state = Connectivity {
+        edges: &edges,
+        queries: &queries,
+        dsu: RollbackDsu::new(6),
+    };
+    dbg!(parallel_binary_search(
+        &mut state,
+        queries.len(),
+        edges.len()
+    ));
+}
+
+struct Connectivity<'a> {
+    edges: &'a [(usize, usize)],
+    queries: &'a [(usize, usize)],
+    dsu: RollbackDsu,
+}
+
+impl PbsState for Connectivity<'_> {
+    fn reset(&mut self) {
+        self.dsu.rollback(0);
+    }
+    fn apply(&mut self, i: usize) {
+        self.dsu.unite(self.edges[i].0, self.edges[i].1);
+    }
+    fn check(&mut self, q: usize) -> bool {
+        self.dsu.same(self.queries[q].0, self.queries[q].1)
+    }
+}
+
+/// 並列二分探索で使う状態．更新を `0, 1, ...` の順に適用していく
+pub trait PbsState {
+    /// 更新を 1 つも適用していない状態に戻す
+    fn reset(&mut self);
+    /// `i` 番目の更新を適用する
+    fn apply(&mut self, i: usize);
+    /// 今の状態で問い合わせ `q` の条件を満たすか．更新を適用するほど満たしやすくなる (単調) こと
+    fn check(&mut self, q: usize) -> bool;
+}
+
+/// 問い合わせ `0..q` それぞれについて，更新 `0..t` のうち最初の `k` 個を適用すると条件を満たすような最小の `k`．
+/// `t` 個すべて適用しても満たさなければ `None`
+///
+/// `reset` を `O(log t)` 回，`apply` を `O(t log t)` 回，`check` を `O(q log t)` 回呼ぶ．
+pub fn parallel_binary_search<S: PbsState>(
+    state: &mut S,
+    q: usize,
+    t: usize,
+) -> Vec<Option<usize>> {
+    // 答えは [lo, hi] にある．t + 1 は満たさないことを表す
+    let mut lo = vec![0; q];
+    let mut hi = vec![t + 1; q];
+    loop {
+        // mid ごとに問い合わせを分ける
+        let mut bucket = vec![vec![]; t + 1];
+        for i in 0..q {
+            if lo[i] < hi[i] {
+                bucket[(lo[i] + hi[i]) / 2].push(i);
+            }
+        }
+        if bucket.iter().all(Vec::is_empty) {
+            break;
+        }
+        state.reset();
+        for (k, qs) in bucket.iter().enumerate() {
+            for &i in qs {
+                if state.check(i) {
+                    hi[i] = k;
+                } else {
+                    lo[i] = k + 1;
+                }
+            }
+            if k < t {
+                state.apply(k);
+            }
+        }
+    }
+    hi.into_iter().map(|k| (k <= t).then_some(k)).collect()
+}
@@ -0,0 +1,153 @@
+use std::{fmt, ops::Index};
+
+fn main() {
+    let p = Permutation::new(vec![1, 2, 0, 4, 3]);
+    let q = Permutation::new(vec![4, 3, 2, 1, 0]);
+    dbg!(p.compose(&q), p.inv(), p.compose(&p.inv()).is_identity());
+    dbg!(p.cycles(), p.order(), p.is_even());
+    dbg!(p.pow(2), p.pow(6), p.pow(-1), p.pow(1_000_000_000_000));
+    let ts = p.to_transpositions();
+    dbg!(&ts, Permutation::from_transpositions(5, &ts) == p);
+    dbg!(p.apply(&['a', 'b', 'c', 'd', 'e']));
+}
+
+/// `0..n` の置換．`p[i]` は `i` の行き先
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    p: Vec<usize>,
+}
+
+impl Permutation {
+    /// `p` が置換でなければ panic
+    pub fn new(p: Vec<usize>) -> Self {
+        let mut seen = vec![false; p.len()];
+        for &x in &p {
+            assert!(x < p.len() && !seen[x], "not a permutation: {:?}", p);
+            seen[x] = true;
+        }
+        Self { p }
+    }
+    pub fn identity(n: usize) -> Self {
+        Self {
+            p: (0..n).collect(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.p.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.p.is_empty()
+    }
+    pub fn as_slice(&self) -> &[usize] {
+        &self.p
+    }
+    pub fn into_vec(self) -> Vec<usize> {
+        self.p
+    }
+    pub fn is_identity(&self) -> bool {
+        self.p.iter().enumerate().all(|(i, &x)| i == x)
+    }
+    /// `self ∘ other`．`i` を `self[other[i]]` に移す
+    pub fn compose(&self, other: &Self) -> Self {
+        assert_eq!(self.len(), other.len());
+        Self {
+            p: other.p.iter().map(|&x| self.p[x]).collect(),
+        }
+    }
+    pub fn inv(&self) -> Self {
+        let mut q = vec![0; self.len()];
+        for (i, &x) in self.p.iter().enumerate() {
+            q[x] = i;
+        }
+        Self { p: q }
+    }
+    /// 巡回置換への分解．各巡回は最小の要素から始まり，最小の要素の昇順に並ぶ．長さ `1` のものも含む
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut res = vec![];
+        for i in 0..self.len() {
+            if seen[i] {
+                continue;
+            }
+            let mut cycle = vec![];
+            let mut j = i;
+            while !seen[j] {
+                seen[j] = true;
+                cycle.push(j);
+                j = self.p[j];
+            }
+            res.push(cycle);
+        }
+        res
+    }
+    /// `k` 乗．`k` は負でもよい
+    pub fn pow(&self, k: i64) -> Self {
+        let mut q = vec![0; self.len()];
+        for cycle in self.cycles() {
+            let len = cycle.len();
+            let k = k.rem_euclid(len as i64) as usize;
+            for (i, &x) in cycle.iter().enumerate() {
+                q[x] = cycle[(i + k) % len];
+            }
+        }
+        Self { p: q }
+    }
+    /// 位数 (巡回の長さの最小公倍数)．`u128` に収まらなければ `None`
+    pub fn order(&self) -> Option<u128> {
+        let gcd = |mut a: u128, mut b: u128| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        self.cycles().iter().try_fold(1u128, |acc, cycle| {
+            let len = cycle.len() as u128;
+            (acc / gcd(acc, len)).checked_mul(len)
+        })
+    }
+    /// 偶置換か
+    pub fn is_even(&self) -> bool {
+        (self.len() - self.cycles().len()).is_multiple_of(2)
+    }
+    /// `from_transpositions` で `self` に戻る互換の列．長さは `n - (巡回の個数)` で最小
+    pub fn to_transpositions(&self) -> Vec<(usize, usize)> {
+        // 互換を右から掛けて恒等置換にしていき，逆順にする
+        let mut q = self.p.clone();
+        let mut res = vec![];
+        for i in 0..q.len() {
+            while q[i] != i {
+                let j = q[i];
+                q.swap(i, j);
+                res.push((i, j));
+            }
+        }
+        res.reverse();
+        res
+    }
+    /// 恒等置換に右から `(a b)` を順に掛けたもの．値の列 `0, 1, ..., n - 1` の位置 `a` と `b` を順に入れ替えたものと同じ
+    pub fn from_transpositions(n: usize, ts: &[(usize, usize)]) -> Self {
+        let mut p: Vec<usize> = (0..n).collect();
+        for &(a, b) in ts {
+            p.swap(a, b);
+        }
+        Self { p }
+    }
+    /// `res[i] = a[p[i]]`
+    pub fn apply<T: Clone>(&self, a: &[T]) -> Vec<T> {
+        assert_eq!(self.len(), a.len());
+        self.p.iter().map(|&x| a[x].clone()).collect()
+    }
+}
+
+impl Index<usize> for Permutation {
+    type Output = usize;
+    fn index(&self, i: usize) -> &usize {
+        &self.p[i]
+    }
+}
+
+impl fmt::Debug for Permutation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.p.fmt(f)
+    }
+}
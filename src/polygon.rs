@@ -0,0 +1,99 @@
+#[allow(dead_code)]
+#[path = "geometry.rs"]
+pub mod geometry;
+
+use geometry::Point;
+
+fn main() {
+    let poly: Vec<Point<i64>> = [(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]
+        .into_iter()
+        .map(|(x, y)| Point::new(x, y))
+        .collect();
+    dbg!(area2(&poly), is_convex(&poly));
+    for (x, y) in [(1, 1), (2, 3), (4, 2), (2, 1), (5, 5)] {
+        dbg!((x, y), contains(&poly, Point::new(x, y)));
+    }
+    let square: Vec<Point<i64>> = [(0, 0), (0, 2), (2, 2), (2, 0)]
+        .into_iter()
+        .map(|(x, y)| Point::new(x, y))
+        .collect();
+    dbg!(area2(&square), is_convex(&square));
+    // 星形 (自己交差)
+    let star: Vec<Point<i64>> = [(0, 3), (-2, -3), (3, 1), (-3, 1), (2, -3)]
+        .into_iter()
+        .map(|(x, y)| Point::new(x, y))
+        .collect();
+    dbg!(is_convex(&star));
+}
+
+/// 符号付き面積の 2 倍．反時計回りなら正
+pub fn area2(polygon: &[Point<i64>]) -> i64 {
+    let n = polygon.len();
+    (0..n).map(|i| polygon[i].cross(polygon[(i + 1) % n])).sum()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Containment {
+    Inside,
+    /// 辺上 (頂点を含む)
+    On,
+    Outside,
+}
+
+/// 点 `p` と多角形の位置関係．多角形は自己交差がなければ向きはどちらでもよい
+pub fn contains(polygon: &[Point<i64>], p: Point<i64>) -> Containment {
+    let n = polygon.len();
+    // 回転数．p から右に伸ばした半直線を，辺が下から上に横切れば +1，上から下なら -1
+    let mut winding = 0;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        let cross = (b - a).cross(p - a);
+        if cross == 0 && (a - p).dot(b - p) <= 0 {
+            return Containment::On;
+        }
+        if a.y <= p.y && p.y < b.y && cross > 0 {
+            winding += 1;
+        } else if b.y <= p.y && p.y < a.y && cross < 0 {
+            winding -= 1;
+        }
+    }
+    if winding != 0 {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
+
+/// 凸多角形か．向きはどちらでもよく，辺上に頂点があってもよい．面積が `0` なら `false`
+pub fn is_convex(polygon: &[Point<i64>]) -> bool {
+    let n = polygon.len();
+    // 長さ 0 の辺は無視する
+    let mut edges: Vec<Point<i64>> = (0..n)
+        .map(|i| polygon[(i + 1) % n] - polygon[i])
+        .filter(|&e| e != Point::new(0, 0))
+        .collect();
+    let m = edges.len();
+    let (mut pos, mut neg) = (false, false);
+    for i in 0..m {
+        match edges[i].cross(edges[(i + 1) % m]).signum() {
+            1 => pos = true,
+            -1 => neg = true,
+            _ => {}
+        }
+    }
+    if pos == neg {
+        return false;
+    }
+    if neg {
+        // 時計回りなら反転する
+        edges.reverse();
+        for e in &mut edges {
+            *e = -*e;
+        }
+    }
+    // 辺の偏角が 1 周だけする (星形のように 2 周以上しない)
+    let descents = (0..m)
+        .filter(|&i| edges[(i + 1) % m].cmp_arg(edges[i]).is_lt())
+        .count();
+    descents == 1
+}
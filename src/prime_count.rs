@@ -0,0 +1,102 @@
+use std::ops::{Mul, Sub};
+
+fn main() {
+    dbg!(prime_count(10), prime_count(100), prime_count(1));
+    dbg!(prime_sum(10), prime_sum(100));
+    dbg!(prime_count(10u64.pow(11)));
+    dbg!(prime_sum(10u64.pow(10)));
+
+    // π(floor(n / i)) を全部
+    let n = 100;
+    let table = PrimeSumTable::new(n, |v| v.saturating_sub(1), |_| 1);
+    dbg!(
+        table.get(n),
+        table.get(n / 3),
+        table.get(n / 50),
+        table.primes()
+    );
+}
+
+/// `v = floor(n / i)` の形のすべての `v` について，`S(v) = Σ_{p <= v, p は素数} f(p)`
+pub struct PrimeSumTable<T> {
+    n: u64,
+    primes: Vec<u64>,
+    /// `lo[v] = S(v)`
+    lo: Vec<T>,
+    /// `hi[i] = S(n / i)`
+    hi: Vec<T>,
+}
+
+impl<T> PrimeSumTable<T>
+where
+    T: Copy + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Lucy のアルゴリズム．`O(n^{3/4})`
+    ///
+    /// `f` は完全乗法的で，`init(v) = Σ_{i=2}^{v} f(i)` でなければならない．
+    pub fn new(n: u64, init: impl Fn(u64) -> T, f: impl Fn(u64) -> T) -> Self {
+        let sq = n.isqrt() as usize;
+        let mut lo: Vec<T> = (0..=sq as u64).map(&init).collect();
+        let mut hi: Vec<T> = (0..=sq as u64)
+            .map(|i| init(n.checked_div(i).unwrap_or(0)))
+            .collect();
+        let mut is_prime = vec![true; sq + 1];
+        let mut primes = vec![];
+        for p in 2..=sq {
+            if !is_prime[p] {
+                continue;
+            }
+            primes.push(p as u64);
+            for q in (p * p..=sq).step_by(p) {
+                is_prime[q] = false;
+            }
+            // 最小素因数が p である合成数を除く
+            let (sp, fp) = (lo[p - 1], f(p as u64));
+            let p2 = p as u64 * p as u64;
+            let m = (n / p2).min(sq as u64) as usize;
+            for i in 1..=m {
+                let d = i * p;
+                let s = if d <= sq {
+                    hi[d]
+                } else {
+                    lo[(n / d as u64) as usize]
+                };
+                hi[i] = hi[i] - fp * (s - sp);
+            }
+            for v in (p * p..=sq).rev() {
+                lo[v] = lo[v] - fp * (lo[v / p] - sp);
+            }
+        }
+        Self { n, primes, lo, hi }
+    }
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+    /// `sqrt(n)` 以下の素数
+    pub fn primes(&self) -> &[u64] {
+        &self.primes
+    }
+    /// `S(v)`．`v` は `floor(n / i)` の形か `sqrt(n)` 以下でなければならない
+    pub fn get(&self, v: u64) -> T {
+        if v < self.lo.len() as u64 {
+            self.lo[v as usize]
+        } else {
+            self.hi[(self.n / v) as usize]
+        }
+    }
+}
+
+/// `n` 以下の素数の個数．`O(n^{3/4})`
+pub fn prime_count(n: u64) -> u64 {
+    PrimeSumTable::new(n, |v| v.saturating_sub(1), |_| 1).get(n)
+}
+
+/// `n` 以下の素数の和．`O(n^{3/4})`
+pub fn prime_sum(n: u64) -> u128 {
+    PrimeSumTable::new(
+        n,
+        |v| (v as u128 * (v as u128 + 1) / 2).saturating_sub(1),
+        |p| p as u128,
+    )
+    .get(n)
+}
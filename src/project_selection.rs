@@ -0,0 +1,92 @@
+#[allow(dead_code)]
+#[path = "max_flow.rs"]
+mod max_flow;
+
+use max_flow::MaxFlow;
+
+fn main() {
+    // 0: 利益 10 の仕事，1, 2: それぞれコスト 3, 4 の道具．仕事 0 には両方の道具が必要．
+    // 3: 選んでも選ばなくてもよいが，選ばないと 2 を失う．1 を選んで 3 を選ばないと 1 を失う．
+    let mut ps = ProjectSelection::new(4);
+    ps.gain_if_chosen(0, 10);
+    ps.gain_if_chosen(1, -3);
+    ps.gain_if_chosen(2, -4);
+    ps.require(0, 1);
+    ps.require(0, 2);
+    ps.gain_if_not_chosen(3, -2);
+    ps.penalty_if_chosen_and_not(1, 3, 1);
+    dbg!(ps.solve());
+}
+
+const INF: i64 = i64::MAX / 4;
+
+/// 各要素を選ぶかどうかを決め，利益を最大化する．いわゆる燃やす埋める．
+pub struct ProjectSelection {
+    n: usize,
+    base: i64,
+    // (from, to, cap)．頂点 n が始点，n + 1 が終点．始点側にあるものが選ばれたもの．
+    edges: Vec<(usize, usize, i64)>,
+}
+
+impl ProjectSelection {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            base: 0,
+            edges: vec![],
+        }
+    }
+    fn source(&self) -> usize {
+        self.n
+    }
+    fn sink(&self) -> usize {
+        self.n + 1
+    }
+    /// `i` を選ぶと `g` 得る．`g` は負でもよい．
+    pub fn gain_if_chosen(&mut self, i: usize, g: i64) {
+        assert!(i < self.n, "index out: {}/{}", i, self.n);
+        if g >= 0 {
+            self.base += g;
+            self.edges.push((self.source(), i, g));
+        } else {
+            self.edges.push((i, self.sink(), -g));
+        }
+    }
+    /// `i` を選ばないと `g` 得る．`g` は負でもよい．
+    pub fn gain_if_not_chosen(&mut self, i: usize, g: i64) {
+        assert!(i < self.n, "index out: {}/{}", i, self.n);
+        if g >= 0 {
+            self.base += g;
+            self.edges.push((i, self.sink(), g));
+        } else {
+            self.edges.push((self.source(), i, -g));
+        }
+    }
+    /// `i` を選び，`j` を選ばないと `c` 失う．`c` は非負．
+    pub fn penalty_if_chosen_and_not(&mut self, i: usize, j: usize, c: i64) {
+        assert!(
+            i < self.n && j < self.n,
+            "index out: ({}, {})/{}",
+            i,
+            j,
+            self.n
+        );
+        assert!(c >= 0, "negative penalty: {}", c);
+        self.edges.push((i, j, c));
+    }
+    /// `i` を選ぶなら `j` も選ばなければならない．
+    pub fn require(&mut self, i: usize, j: usize) {
+        self.penalty_if_chosen_and_not(i, j, INF);
+    }
+    /// (最大の利益, 各要素を選ぶかどうか)
+    pub fn solve(&self) -> (i64, Vec<bool>) {
+        let mut g = MaxFlow::new(self.n + 2);
+        for &(from, to, cap) in &self.edges {
+            g.add_edge(from, to, cap);
+        }
+        let cut = g.flow(self.source(), self.sink());
+        let mut chosen = g.min_cut(self.source());
+        chosen.truncate(self.n);
+        (self.base - cut, chosen)
+    }
+}
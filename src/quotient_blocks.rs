@@ -0,0 +1,52 @@
+fn main() {
+    dbg!(quotient_blocks(10).collect::<Vec<_>>());
+    dbg!(
+        quotient_blocks(0).count(),
+        quotient_blocks(1).collect::<Vec<_>>()
+    );
+    // Σ_{i=1}^{n} floor(n / i) (= Σ_{k=1}^{n} (k の約数の個数))
+    let n = 10u64.pow(12);
+    dbg!(quotient_blocks(n)
+        .map(|(l, r, q)| (r - l + 1) * q)
+        .sum::<u64>());
+    // Σ_{i=1}^{n} i floor(n / i) (= Σ_{k=1}^{n} (k の約数の和)) (mod 998244353)
+    let m = 998244353u128;
+    let n = 10u64.pow(12);
+    let sum = |x: u64| x as u128 * (x as u128 + 1) / 2 % m;
+    dbg!(
+        quotient_blocks(n)
+            .map(|(l, r, q)| (sum(r) + m - sum(l - 1)) * (q as u128 % m) % m)
+            .sum::<u128>()
+            % m
+    );
+    // 2 sqrt(n) 個程度
+    dbg!(quotient_blocks(10u64.pow(6)).count());
+}
+
+/// `floor(n / i)` が一定 (`q`) である極大な区間 `l <= i <= r` を `(l, r, q)` で，`i = 1` から順に
+///
+/// 区間は `O(sqrt(n))` 個．
+pub fn quotient_blocks(n: u64) -> QuotientBlocks {
+    QuotientBlocks { n, l: 1 }
+}
+
+pub struct QuotientBlocks {
+    n: u64,
+    l: u64,
+}
+
+impl Iterator for QuotientBlocks {
+    type Item = (u64, u64, u64);
+    fn next(&mut self) -> Option<(u64, u64, u64)> {
+        if self.l == 0 || self.l > self.n {
+            return None;
+        }
+        let l = self.l;
+        let q = self.n / l;
+        // floor(n / i) = q となる最大の i
+        let r = self.n / q;
+        // r = u64::MAX のとき，l は 0 になって終わる
+        self.l = r.wrapping_add(1);
+        Some((l, r, q))
+    }
+}
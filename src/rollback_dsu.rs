@@ -0,0 +1,86 @@
+fn main() {
+    let mut dsu = RollbackDsu::new(5);
+    dsu.unite(0, 1);
+    let s = dsu.snapshot();
+    dsu.unite(1, 2);
+    dsu.unite(3, 4);
+    dbg!(dsu.same(0, 2), dsu.size(0), dsu.count());
+    dsu.rollback(s);
+    dbg!(dsu.same(0, 2), dsu.size(0), dsu.count());
+    dbg!(dsu.undo(), dsu.undo(), dsu.count());
+}
+
+/// 操作を取り消せる Union-Find．経路圧縮をしないので各操作 `O(log n)`
+#[derive(Clone, Debug)]
+pub struct RollbackDsu {
+    /// 根なら `-(サイズ)`，そうでなければ親
+    parent: Vec<isize>,
+    count: usize,
+    /// `unite` ごとに，つないだ `(根, 子になった根, 子になった根の parent)`．同じ連結成分なら `None`
+    history: Vec<Option<(usize, usize, isize)>>,
+}
+
+impl RollbackDsu {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: vec![-1; n],
+            count: n,
+            history: vec![],
+        }
+    }
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] >= 0 {
+            x = self.parent[x] as usize;
+        }
+        x
+    }
+    /// 別の連結成分だったら `true`
+    pub fn unite(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            self.history.push(None);
+            return false;
+        }
+        if self.parent[a] > self.parent[b] {
+            (a, b) = (b, a);
+        }
+        self.history.push(Some((a, b, self.parent[b])));
+        self.parent[a] += self.parent[b];
+        self.parent[b] = a as isize;
+        self.count -= 1;
+        true
+    }
+    pub fn same(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    /// `x` を含む連結成分の大きさ
+    pub fn size(&self, x: usize) -> usize {
+        -self.parent[self.find(x)] as usize
+    }
+    /// 連結成分の個数
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// 最後の `unite` を取り消す．取り消すものがなければ `false`
+    pub fn undo(&mut self) -> bool {
+        let Some(last) = self.history.pop() else {
+            return false;
+        };
+        if let Some((a, b, pb)) = last {
+            self.parent[a] -= pb;
+            self.parent[b] = pb;
+            self.count += 1;
+        }
+        true
+    }
+    /// 今の状態．`rollback` に渡す
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+    /// `snapshot` をとったときの状態に戻す
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
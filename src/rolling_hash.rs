@@ -0,0 +1,128 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+fn main() {
+    let s = RollingHash::new(b"abracadabra");
+    dbg!(s.hash(0..4) == s.hash(7..11), s.hash(0..4) == s.hash(1..5));
+    dbg!(s.concat(s.hash(0..2), s.hash(2..4), 2) == s.hash(0..4));
+    dbg!(s.lcp(0, &s, 7), s.compare(0..4, &s, 3..7));
+
+    // 別の列とも比較できる
+    let t = RollingHash::new(&"cadabra".chars().collect::<Vec<_>>());
+    dbg!(s.hash(4..11) == t.hash(0..7), s.lcp(3, &t, 0));
+    dbg!(s.compare(0..11, &t, 0..7));
+}
+
+pub const MOD: u64 = (1 << 61) - 1;
+
+fn mul(a: u64, b: u64) -> u64 {
+    let t = a as u128 * b as u128;
+    let t = (t >> 61) as u64 + (t as u64 & MOD);
+    if t >= MOD {
+        t - MOD
+    } else {
+        t
+    }
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    let t = a + b;
+    if t >= MOD {
+        t - MOD
+    } else {
+        t
+    }
+}
+
+fn pow(mut a: u64, mut n: u64) -> u64 {
+    let mut res = 1;
+    while n > 0 {
+        if n & 1 == 1 {
+            res = mul(res, a);
+        }
+        a = mul(a, a);
+        n >>= 1;
+    }
+    res
+}
+
+/// 実行ごとにランダムに決まる基数．すべての `RollingHash` で共通．
+pub fn base() -> u64 {
+    static BASE: OnceLock<u64> = OnceLock::new();
+    *BASE.get_or_init(|| {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(0);
+        hasher.finish() % (MOD - 256) + 256
+    })
+}
+
+pub struct RollingHash {
+    s: Vec<u64>,
+    // hash[i]: s[..i] のハッシュ値，pow[i]: base^i
+    hash: Vec<u64>,
+    pow: Vec<u64>,
+}
+
+impl RollingHash {
+    pub fn new<T: Copy + Into<u64>>(s: &[T]) -> Self {
+        let b = base();
+        let s: Vec<u64> = s.iter().map(|&c| c.into() % MOD).collect();
+        let mut hash = vec![0; s.len() + 1];
+        let mut pow = vec![1; s.len() + 1];
+        for (i, &c) in s.iter().enumerate() {
+            hash[i + 1] = add(mul(hash[i], b), c);
+            pow[i + 1] = mul(pow[i], b);
+        }
+        Self { s, hash, pow }
+    }
+    pub fn len(&self) -> usize {
+        self.s.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+    /// `s[range]` のハッシュ値
+    pub fn hash(&self, range: Range<usize>) -> u64 {
+        let Range { start, end } = range;
+        assert!(start <= end, "invalid range: {}..{}", start, end);
+        assert!(end <= self.len(), "index out: {}/{}", end, self.len());
+        add(
+            self.hash[end],
+            MOD - mul(self.hash[start], self.pow[end - start]),
+        )
+    }
+    /// ハッシュ値 `a` の列の後ろに，長さ `b_len` でハッシュ値 `b` の列をつなげたもののハッシュ値
+    pub fn concat(&self, a: u64, b: u64, b_len: usize) -> u64 {
+        let p = match self.pow.get(b_len) {
+            Some(&p) => p,
+            None => pow(base(), b_len as u64),
+        };
+        add(mul(a, p), b)
+    }
+    /// `self[i..]` と `other[j..]` の最長共通接頭辞の長さ
+    pub fn lcp(&self, i: usize, other: &Self, j: usize) -> usize {
+        let (mut ok, mut ng) = (0, (self.len() - i).min(other.len() - j) + 1);
+        while ng - ok > 1 {
+            let mid = (ok + ng) / 2;
+            if self.hash(i..i + mid) == other.hash(j..j + mid) {
+                ok = mid;
+            } else {
+                ng = mid;
+            }
+        }
+        ok
+    }
+    /// `self[a]` と `other[b]` の辞書順比較
+    pub fn compare(&self, a: Range<usize>, other: &Self, b: Range<usize>) -> Ordering {
+        let (a_len, b_len) = (a.len(), b.len());
+        let l = self.lcp(a.start, other, b.start).min(a_len).min(b_len);
+        if l == a_len || l == b_len {
+            a_len.cmp(&b_len)
+        } else {
+            self.s[a.start + l].cmp(&other.s[b.start + l])
+        }
+    }
+}
@@ -0,0 +1,120 @@
+#[allow(dead_code)]
+#[path = "convex_hull.rs"]
+pub mod convex_hull;
+
+use convex_hull::{convex_hull, geometry::Point};
+
+fn main() {
+    let ps: Vec<Point<i64>> = [(0, 0), (4, 0), (5, 2), (3, 5), (0, 3), (2, 2), (1, 1)]
+        .into_iter()
+        .map(|(x, y)| Point::new(x, y))
+        .collect();
+    let hull = convex_hull(&ps, false);
+    dbg!(&hull);
+    dbg!(convex_diameter(&hull), convex_width(&hull));
+    dbg!(min_area_rectangle(&hull));
+    // 正方形を 45 度回したもの
+    let diamond: Vec<Point<i64>> = [(1, 0), (2, 1), (1, 2), (0, 1)]
+        .into_iter()
+        .map(|(x, y)| Point::new(x, y))
+        .collect();
+    dbg!(convex_diameter(&diamond), min_area_rectangle(&diamond));
+}
+
+/// 凸多角形の直径 (最も遠い 2 頂点の距離) の 2 乗と，その 2 頂点の番号
+///
+/// `hull` は `convex_hull(ps, false)` の出力のように，反時計回りで 3 点が一直線上にないものでなければならない．
+pub fn convex_diameter(hull: &[Point<i64>]) -> (i64, (usize, usize)) {
+    let n = hull.len();
+    assert!(n >= 1);
+    let at = |i: usize| hull[i % n];
+    let mut best = (0, (0, 0));
+    let mut j = 1;
+    for i in 0..n {
+        // 辺 i, i + 1 から最も遠い頂点 j (対蹠点) まで進む
+        let e = at(i + 1) - at(i);
+        while e.cross(at(j + 1) - at(j)) > 0 {
+            j += 1;
+        }
+        for k in [i, i + 1] {
+            let d = (at(j) - at(k)).norm2();
+            if d > best.0 {
+                best = (d, (k % n, j % n));
+            }
+        }
+    }
+    best
+}
+
+/// 凸多角形の幅 (平行な 2 直線で挟むときの間隔の最小値)．条件は `convex_diameter` と同じ
+pub fn convex_width(hull: &[Point<i64>]) -> f64 {
+    let n = hull.len();
+    if n <= 2 {
+        return 0.0;
+    }
+    let at = |i: usize| hull[i % n];
+    // 辺 i を含む直線と，そこから最も遠い頂点の距離の最小値．(外積)^2 / |辺|^2 を有理数で比べる
+    let mut best: Option<(i128, i128)> = None;
+    let mut j = 1;
+    for i in 0..n {
+        let e = at(i + 1) - at(i);
+        j = j.max(i + 1);
+        while e.cross(at(j + 1) - at(j)) > 0 {
+            j += 1;
+        }
+        let h = e.cross(at(j) - at(i)) as i128;
+        let (num, den) = (h * h, e.norm2() as i128);
+        if best.is_none_or(|(bn, bd)| num * bd < bn * den) {
+            best = Some((num, den));
+        }
+    }
+    let (num, den) = best.unwrap();
+    (num as f64 / den as f64).sqrt()
+}
+
+/// 凸多角形を囲む長方形で面積が最小のものの面積と，その頂点 (反時計回り)．条件は `convex_diameter` と同じ
+///
+/// 最小の長方形の辺の 1 つは凸多角形の辺を含むので，各辺について調べる．
+pub fn min_area_rectangle(hull: &[Point<i64>]) -> (f64, [Point<f64>; 4]) {
+    let n = hull.len();
+    assert!(n >= 1);
+    if n == 1 {
+        return (0.0, [hull[0].to_f64(); 4]);
+    }
+    let at = |i: usize| hull[i % n];
+    let mut best: Option<(f64, [Point<f64>; 4])> = None;
+    // k: 辺の向きで最も先，j: 辺から最も遠い，l: 辺の向きで最も後ろ
+    let (mut k, mut j, mut l) = (1, 1, 1);
+    for i in 0..n {
+        let e = at(i + 1) - at(i);
+        k = k.max(i + 1);
+        while e.dot(at(k + 1) - at(k)) > 0 {
+            k += 1;
+        }
+        j = j.max(k);
+        while e.cross(at(j + 1) - at(j)) > 0 {
+            j += 1;
+        }
+        l = l.max(j);
+        while e.dot(at(l + 1) - at(l)) < 0 {
+            l += 1;
+        }
+        let len = e.to_f64().abs();
+        let (u, base) = (e.to_f64() * (1.0 / len), at(i).to_f64());
+        let normal = Point::new(-u.y, u.x);
+        let lo = u.dot(at(l).to_f64() - base);
+        let hi = u.dot(at(k).to_f64() - base);
+        let h = e.cross(at(j) - at(i)) as f64 / len;
+        let area = (hi - lo) * h;
+        if best.is_none_or(|(a, _)| area < a) {
+            let corners = [
+                base + u * lo,
+                base + u * hi,
+                base + u * hi + normal * h,
+                base + u * lo + normal * h,
+            ];
+            best = Some((area, corners));
+        }
+    }
+    best.unwrap()
+}
@@ -0,0 +1,55 @@
+fn main() {
+    let rl = run_length(b"aaabccaa");
+    dbg!(&rl, run_length_decode(&rl));
+    dbg!(runs("hellooo world".chars()).max_by_key(|&(_, k)| k));
+    dbg!(runs((0..10).map(|x| x / 3)).collect::<Vec<_>>());
+}
+
+/// 連続する等しい要素をまとめて `(要素, 個数)` に
+pub fn run_length<T: PartialEq + Clone>(a: &[T]) -> Vec<(T, usize)> {
+    runs(a.iter().cloned()).collect()
+}
+
+/// `run_length` の逆
+pub fn run_length_decode<T: Clone>(rl: &[(T, usize)]) -> Vec<T> {
+    rl.iter()
+        .flat_map(|(x, k)| std::iter::repeat_n(x.clone(), *k))
+        .collect()
+}
+
+/// 連続する等しい要素をまとめて `(要素, 個数)` を返すイテレータ．要素は最初のものを返す
+pub fn runs<I: IntoIterator>(iter: I) -> Runs<I::IntoIter>
+where
+    I::Item: PartialEq,
+{
+    let mut iter = iter.into_iter();
+    Runs {
+        next: iter.next(),
+        iter,
+    }
+}
+
+pub struct Runs<I: Iterator> {
+    iter: I,
+    /// 次のまとまりの最初の要素
+    next: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Runs<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.next.take()?;
+        let mut k = 1;
+        for y in self.iter.by_ref() {
+            if y != x {
+                self.next = Some(y);
+                break;
+            }
+            k += 1;
+        }
+        Some((x, k))
+    }
+}
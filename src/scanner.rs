@@ -0,0 +1,227 @@
+use std::io::{BufRead, Read, Write};
+
+fn main() {
+    let mut sc = Scanner::new(&b"3 2\n1 2 3\nabc\n1 3\n2 3\n2 -1 -2\n"[..]);
+    crate::input! {
+        from sc,
+        n: usize,
+        m: usize,
+        a: [i64; n],
+        s: Bytes,
+        edges: [(Usize1, Usize1); m],
+        b: [i64],
+    }
+    dbg!(n, a, s, edges, b);
+
+    // 二分探索で数当て (答えは 5)．応答は "<" か ">=" が 1 行ずつ返る
+    let mut sc = Scanner::new_interactive(&b">=\n<\n<\n"[..]);
+    let (mut lo, mut hi) = (1, 9);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        crate::query!("? {}", mid);
+        crate::input! { from sc, res: String }
+        if res == "<" {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    crate::query!("! {}", lo);
+}
+
+/// 空白区切りのトークンを読む
+pub struct Scanner {
+    buf: Vec<u8>,
+    pos: usize,
+    /// インタラクティブなら，`buf` を読み終えるたびにここから 1 行ずつ読む
+    reader: Option<Box<dyn BufRead>>,
+}
+
+impl Scanner {
+    /// 入力をはじめにすべて読んでおく
+    pub fn new(mut reader: impl Read) -> Self {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf).unwrap();
+        Self {
+            buf,
+            pos: 0,
+            reader: None,
+        }
+    }
+    /// 標準入力から
+    pub fn stdin() -> Self {
+        Self::new(std::io::stdin().lock())
+    }
+    /// 入力を必要になってから 1 行ずつ読む．インタラクティブな問題で
+    pub fn new_interactive(reader: impl BufRead + 'static) -> Self {
+        Self {
+            buf: vec![],
+            pos: 0,
+            reader: Some(Box::new(reader)),
+        }
+    }
+    /// 標準入力から，インタラクティブに
+    pub fn stdin_interactive() -> Self {
+        Self::new_interactive(std::io::stdin().lock())
+    }
+    /// 次のトークン．入力が終わっていたら panic
+    pub fn token(&mut self) -> &[u8] {
+        loop {
+            while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+                self.pos += 1;
+            }
+            if self.pos < self.buf.len() {
+                break;
+            }
+            let Some(reader) = &mut self.reader else {
+                break;
+            };
+            self.buf.clear();
+            self.pos = 0;
+            if reader.read_until(b'\n', &mut self.buf).unwrap() == 0 {
+                break;
+            }
+        }
+        let start = self.pos;
+        while self.pos < self.buf.len() && !self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        assert!(start < self.pos, "unexpected end of input");
+        &self.buf[start..self.pos]
+    }
+    pub fn read<T: Readable>(&mut self) -> T::Output {
+        T::read(self)
+    }
+}
+
+/// `Scanner` で読めるもの．`Output` は読んだ結果の型
+pub trait Readable {
+    type Output;
+    fn read(sc: &mut Scanner) -> Self::Output;
+}
+
+macro_rules! impl_readable_from_str {
+    ($($t:ty)*) => {$(
+        impl Readable for $t {
+            type Output = $t;
+            fn read(sc: &mut Scanner) -> $t {
+                std::str::from_utf8(sc.token()).unwrap().parse().unwrap()
+            }
+        }
+    )*};
+}
+
+impl_readable_from_str!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64 bool char String);
+
+/// 1-indexed の値を 0-indexed の `usize` に
+pub enum Usize1 {}
+
+impl Readable for Usize1 {
+    type Output = usize;
+    fn read(sc: &mut Scanner) -> usize {
+        sc.read::<usize>()
+            .checked_sub(1)
+            .expect("Usize1 must be at least 1")
+    }
+}
+
+/// 1-indexed の値を 0-indexed の `isize` に
+pub enum Isize1 {}
+
+impl Readable for Isize1 {
+    type Output = isize;
+    fn read(sc: &mut Scanner) -> isize {
+        sc.read::<isize>() - 1
+    }
+}
+
+/// トークンを `Vec<u8>` で
+pub enum Bytes {}
+
+impl Readable for Bytes {
+    type Output = Vec<u8>;
+    fn read(sc: &mut Scanner) -> Vec<u8> {
+        sc.token().to_vec()
+    }
+}
+
+/// トークンを `Vec<char>` で
+pub enum Chars {}
+
+impl Readable for Chars {
+    type Output = Vec<char>;
+    fn read(sc: &mut Scanner) -> Vec<char> {
+        std::str::from_utf8(sc.token()).unwrap().chars().collect()
+    }
+}
+
+macro_rules! impl_readable_tuple {
+    ($($t:ident)*) => {
+        impl<$($t: Readable),*> Readable for ($($t,)*) {
+            type Output = ($($t::Output,)*);
+            fn read(sc: &mut Scanner) -> Self::Output {
+                ($($t::read(sc),)*)
+            }
+        }
+    };
+}
+
+impl_readable_tuple!(A);
+impl_readable_tuple!(A B);
+impl_readable_tuple!(A B C);
+impl_readable_tuple!(A B C D);
+impl_readable_tuple!(A B C D E);
+impl_readable_tuple!(A B C D E F);
+
+/// 標準出力を flush する
+pub fn flush() {
+    std::io::stdout().flush().unwrap();
+}
+
+/// `println!` してから flush する．インタラクティブな問題で質問を送るときに
+#[macro_export]
+macro_rules! query {
+    ($($arg:tt)*) => {{
+        println!($($arg)*);
+        ::std::io::Write::flush(&mut ::std::io::stdout()).unwrap();
+    }};
+}
+
+/// `input! { from sc, n: usize, mut a: [i64; n], ... }`
+#[macro_export]
+macro_rules! input {
+    (from $sc:expr, $($rest:tt)*) => {
+        $crate::input_inner!($sc, $($rest)*)
+    };
+}
+
+#[macro_export]
+macro_rules! input_inner {
+    ($sc:expr $(,)?) => {};
+    ($sc:expr, mut $var:ident: $t:tt $($rest:tt)*) => {
+        let mut $var = $crate::read_value!($sc, $t);
+        $crate::input_inner!($sc $($rest)*)
+    };
+    ($sc:expr, $var:ident: $t:tt $($rest:tt)*) => {
+        let $var = $crate::read_value!($sc, $t);
+        $crate::input_inner!($sc $($rest)*)
+    };
+}
+
+/// `[T; n]` は長さ `n` の `Vec`，`[T]` は長さを読んでから `Vec`，`(T, U, ...)` はタプル
+#[macro_export]
+macro_rules! read_value {
+    ($sc:expr, [$t:tt; $n:expr]) => {
+        (0..$n).map(|_| $crate::read_value!($sc, $t)).collect::<Vec<_>>()
+    };
+    ($sc:expr, [$t:tt]) => {{
+        let n = $sc.read::<usize>();
+        $crate::read_value!($sc, [$t; n])
+    }};
+    ($sc:expr, ($($t:tt),* $(,)?)) => {
+        ($($crate::read_value!($sc, $t),)*)
+    };
+    ($sc:expr, $t:ty) => {
+        $sc.read::<$t>()
+    };
+}
@@ -0,0 +1,96 @@
+fn main() {
+    // x^2 >= 2 * 10^18 となる最小の x
+    dbg!(binary_search(2_000_000_000, 0, |x| x * x >= 2 * 10i64.pow(18)));
+    // ok > ng でもよい: x^3 <= 10^18 となる最大の x
+    dbg!(binary_search(0, 1_000_001, |x| x * x * x <= 10i64.pow(18)));
+    dbg!(binary_search_f64(0.0, 2.0, |x| x * x <= 2.0));
+
+    dbg!(ternary_search_min(-100, 100, |x| (x - 7) * (x - 7) + 3));
+    dbg!(ternary_search_max(0, 10, |x| [
+        1, 3, 5, 5, 5, 4, 2, 0, -1, -3, -5
+    ][x as usize]));
+    dbg!(
+        ternary_search_min_f64(-10.0, 10.0, |x| (x - 1.5).abs() + 1.0),
+        ternary_search_max_f64(0.0, std::f64::consts::PI, f64::sin)
+    );
+}
+
+/// `pred(ok)` が `true`，`pred(ng)` が `false` で，その間で `pred` が単調なとき，`pred` が `true` となる境界の値
+///
+/// `ok < ng` なら `pred(x)` が `true` となる最大の `x`，`ok > ng` なら最小の `x`．`pred` は `ok` と `ng` の間だけで呼ぶ．
+pub fn binary_search(mut ok: i64, mut ng: i64, mut pred: impl FnMut(i64) -> bool) -> i64 {
+    while ok.abs_diff(ng) > 1 {
+        // あふれない (ok + ng) / 2 の切り捨て
+        let mid = (ok & ng) + ((ok ^ ng) >> 1);
+        if pred(mid) {
+            ok = mid;
+        } else {
+            ng = mid;
+        }
+    }
+    ok
+}
+
+/// `binary_search` の `f64` 版．区間を 100 回半分にする
+pub fn binary_search_f64(mut ok: f64, mut ng: f64, mut pred: impl FnMut(f64) -> bool) -> f64 {
+    for _ in 0..100 {
+        let mid = (ok + ng) / 2.0;
+        if pred(mid) {
+            ok = mid;
+        } else {
+            ng = mid;
+        }
+    }
+    ok
+}
+
+/// `[lo, hi]` で `f` を最小にする `(x, f(x))`．複数あれば最小の `x`．`O(log(hi - lo))`
+///
+/// `f` は狭義単調減少のあと狭義単調増加 (最小値をとる区間は平らでもよい)．
+pub fn ternary_search_min<T: PartialOrd>(
+    lo: i64,
+    hi: i64,
+    mut f: impl FnMut(i64) -> T,
+) -> (i64, T) {
+    assert!(lo <= hi);
+    // f(x) <= f(x + 1) となる最小の x
+    let x = binary_search(hi, lo - 1, |x| x == hi || f(x) <= f(x + 1));
+    (x, f(x))
+}
+
+/// `[lo, hi]` で `f` を最大にする `(x, f(x))`．複数あれば最小の `x`．`O(log(hi - lo))`
+///
+/// `f` は狭義単調増加のあと狭義単調減少 (最大値をとる区間は平らでもよい)．
+pub fn ternary_search_max<T: PartialOrd>(
+    lo: i64,
+    hi: i64,
+    mut f: impl FnMut(i64) -> T,
+) -> (i64, T) {
+    assert!(lo <= hi);
+    let x = binary_search(hi, lo - 1, |x| x == hi || f(x) >= f(x + 1));
+    (x, f(x))
+}
+
+/// `[lo, hi]` で `f` を最小にする `(x, f(x))`．区間を 100 回 `2 / 3` 倍にする
+pub fn ternary_search_min_f64(
+    mut lo: f64,
+    mut hi: f64,
+    mut f: impl FnMut(f64) -> f64,
+) -> (f64, f64) {
+    for _ in 0..100 {
+        let (m1, m2) = ((lo * 2.0 + hi) / 3.0, (lo + hi * 2.0) / 3.0);
+        if f(m1) < f(m2) {
+            hi = m2;
+        } else {
+            lo = m1;
+        }
+    }
+    let x = (lo + hi) / 2.0;
+    (x, f(x))
+}
+
+/// `[lo, hi]` で `f` を最大にする `(x, f(x))`．区間を 100 回 `2 / 3` 倍にする
+pub fn ternary_search_max_f64(lo: f64, hi: f64, mut f: impl FnMut(f64) -> f64) -> (f64, f64) {
+    let (x, y) = ternary_search_min_f64(lo, hi, |x| -f(x));
+    (x, -y)
+}
@@ -0,0 +1,116 @@
+fn main() {
+    let sieve = Sieve::new(100);
+    dbg!(&sieve.primes()[..10]);
+    dbg!(sieve.is_prime(97), sieve.is_prime(91), sieve.spf(91));
+    dbg!(sieve.factorize(72), sieve.factorize(1), sieve.divisors(72));
+    dbg!(&sieve.phi_table()[..11], &sieve.mu_table()[..11]);
+    dbg!(sieve.phi(100), sieve.mu(30));
+}
+
+/// 線形篩．`0..=n` の最小素因数，Euler の φ 関数，Möbius 関数
+pub struct Sieve {
+    spf: Vec<usize>,
+    primes: Vec<usize>,
+    phi: Vec<usize>,
+    mu: Vec<i8>,
+}
+
+impl Sieve {
+    pub fn new(n: usize) -> Self {
+        let mut spf = vec![0; n + 1];
+        let mut primes = vec![];
+        let mut phi = vec![0; n + 1];
+        let mut mu = vec![0; n + 1];
+        if n >= 1 {
+            phi[1] = 1;
+            mu[1] = 1;
+        }
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i;
+                primes.push(i);
+                phi[i] = i - 1;
+                mu[i] = -1;
+            }
+            // i * p (p <= spf[i]) の最小素因数は p
+            for &p in &primes {
+                if p > spf[i] || i * p > n {
+                    break;
+                }
+                spf[i * p] = p;
+                if p == spf[i] {
+                    phi[i * p] = phi[i] * p;
+                    mu[i * p] = 0;
+                } else {
+                    phi[i * p] = phi[i] * (p - 1);
+                    mu[i * p] = -mu[i];
+                }
+            }
+        }
+        Self {
+            spf,
+            primes,
+            phi,
+            mu,
+        }
+    }
+    pub fn n(&self) -> usize {
+        self.spf.len() - 1
+    }
+    /// `n` 以下の素数を昇順に
+    pub fn primes(&self) -> &[usize] {
+        &self.primes
+    }
+    pub fn is_prime(&self, x: usize) -> bool {
+        x >= 2 && self.spf[x] == x
+    }
+    /// 最小素因数．`x >= 2`
+    pub fn spf(&self, x: usize) -> usize {
+        assert!(x >= 2);
+        self.spf[x]
+    }
+    /// `(素因数, 指数)` を素因数の昇順に．`1 <= x <= n`
+    pub fn factorize(&self, mut x: usize) -> Vec<(usize, u32)> {
+        assert!(x >= 1);
+        let mut res: Vec<(usize, u32)> = vec![];
+        while x > 1 {
+            let p = self.spf[x];
+            match res.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => res.push((p, 1)),
+            }
+            x /= p;
+        }
+        res
+    }
+    /// 約数を昇順に．`1 <= x <= n`
+    pub fn divisors(&self, x: usize) -> Vec<usize> {
+        let mut res = vec![1];
+        for (p, e) in self.factorize(x) {
+            let len = res.len();
+            let mut pk = 1;
+            for _ in 0..e {
+                pk *= p;
+                for i in 0..len {
+                    res.push(res[i] * pk);
+                }
+            }
+        }
+        res.sort_unstable();
+        res
+    }
+    pub fn phi(&self, x: usize) -> usize {
+        self.phi[x]
+    }
+    pub fn mu(&self, x: usize) -> i8 {
+        self.mu[x]
+    }
+    /// `phi_table()[0]` は `0`
+    pub fn phi_table(&self) -> &[usize] {
+        &self.phi
+    }
+    /// `mu_table()[0]` は `0`
+    pub fn mu_table(&self) -> &[i8] {
+        &self.mu
+    }
+}
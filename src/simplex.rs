@@ -0,0 +1,146 @@
+fn main() {
+    // maximize 3x + 2y  s.t. x + y <= 4, x + 3y <= 6, x <= 3
+    let a = vec![vec![1.0, 1.0], vec![1.0, 3.0], vec![1.0, 0.0]];
+    dbg!(Simplex::new(&a, &[4.0, 6.0, 3.0], &[3.0, 2.0]).solve());
+    // b に負があってもよい: x + y >= 2 (-x - y <= -2), x <= 1, y <= 1 で x + y を最小化
+    let a = vec![vec![-1.0, -1.0], vec![1.0, 0.0], vec![0.0, 1.0]];
+    dbg!(Simplex::new(&a, &[-2.0, 1.0, 1.0], &[-1.0, -1.0]).solve());
+    // 実行不能: x <= -1
+    dbg!(Simplex::new(&[vec![1.0]], &[-1.0], &[1.0]).solve());
+    // 非有界: x - y <= 1 で x を最大化
+    dbg!(Simplex::new(&[vec![1.0, -1.0]], &[1.0], &[1.0, 0.0]).solve());
+}
+
+const EPS: f64 = 1e-9;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LpResult {
+    /// 最適値と最適解
+    Optimal(f64, Vec<f64>),
+    Infeasible,
+    Unbounded,
+}
+
+/// `Ax <= b`, `x >= 0` のもとで `cx` を最大化する．単体法 (Bland の規則)
+pub struct Simplex {
+    m: usize,
+    n: usize,
+    /// 基底変数．変数 `0..n` は元の変数，`n..n + m` はスラック変数，`n + m` は補助変数
+    basis: Vec<usize>,
+    /// 非基底変数 (`n + 1` 個)
+    non_basis: Vec<usize>,
+    /// `(m + 2) × (n + 2)` の表．`m` 行目が目的関数，`m + 1` 行目が補助問題の目的関数．`n + 1` 列目が定数項
+    d: Vec<Vec<f64>>,
+}
+
+impl Simplex {
+    /// `a` は `m × n`，`b` は長さ `m`，`c` は長さ `n`
+    pub fn new(a: &[Vec<f64>], b: &[f64], c: &[f64]) -> Self {
+        let (m, n) = (b.len(), c.len());
+        assert_eq!(a.len(), m);
+        assert!(a.iter().all(|row| row.len() == n), "not a matrix");
+        let mut d = vec![vec![0.0; n + 2]; m + 2];
+        for i in 0..m {
+            d[i][..n].copy_from_slice(&a[i]);
+            // 補助変数の係数
+            d[i][n] = -1.0;
+            d[i][n + 1] = b[i];
+        }
+        for j in 0..n {
+            d[m][j] = -c[j];
+        }
+        d[m + 1][n] = 1.0;
+        Self {
+            m,
+            n,
+            basis: (n..n + m).collect(),
+            non_basis: (0..n).chain([n + m]).collect(),
+            d,
+        }
+    }
+    /// `r` 行目の基底変数と `s` 列目の非基底変数を入れ替える
+    fn pivot(&mut self, r: usize, s: usize) {
+        let inv = 1.0 / self.d[r][s];
+        let row_r = self.d[r].clone();
+        for (i, row) in self.d.iter_mut().enumerate() {
+            if i != r && row[s].abs() > EPS {
+                let coef = row[s] * inv;
+                for (x, y) in row.iter_mut().zip(&row_r) {
+                    *x -= y * coef;
+                }
+                row[s] = -coef;
+            } else if i != r {
+                row[s] *= -inv;
+            }
+        }
+        for x in &mut self.d[r] {
+            *x *= inv;
+        }
+        self.d[r][s] = inv;
+        std::mem::swap(&mut self.basis[r], &mut self.non_basis[s]);
+    }
+    /// `obj` 行目を目的関数として最適化する．非有界なら `false`
+    fn run(&mut self, obj: usize, skip: usize) -> bool {
+        loop {
+            // 入る変数: 目的関数を増やせる変数のうち番号最小 (Bland の規則)
+            let Some(s) = (0..=self.n)
+                .filter(|&j| self.non_basis[j] != skip && self.d[obj][j] < -EPS)
+                .min_by_key(|&j| self.non_basis[j])
+            else {
+                return true;
+            };
+            // 出る変数: 比が最小のもの．同じなら番号最小
+            let mut r = None;
+            for i in 0..self.m {
+                if self.d[i][s] <= EPS {
+                    continue;
+                }
+                let ratio = self.d[i][self.n + 1] / self.d[i][s];
+                r = match r {
+                    Some((best, k)) if (best, self.basis[k]) <= (ratio, self.basis[i]) => {
+                        Some((best, k))
+                    }
+                    _ => Some((ratio, i)),
+                };
+            }
+            let Some((_, r)) = r else {
+                return false;
+            };
+            self.pivot(r, s);
+        }
+    }
+    pub fn solve(mut self) -> LpResult {
+        let (m, n) = (self.m, self.n);
+        let aux = n + m;
+        // b に負があれば，補助変数を使って実行可能基底解を探す
+        if let Some(r) = (0..m).min_by(|&i, &j| self.d[i][n + 1].total_cmp(&self.d[j][n + 1])) {
+            if self.d[r][n + 1] < -EPS {
+                self.pivot(r, n);
+                if !self.run(m + 1, usize::MAX) || self.d[m + 1][n + 1] < -EPS {
+                    return LpResult::Infeasible;
+                }
+                // 補助変数が基底に残っていたら追い出す
+                if let Some(i) = (0..m).find(|&i| self.basis[i] == aux) {
+                    let s = (0..=n)
+                        .min_by(|&j, &k| {
+                            self.d[i][j]
+                                .total_cmp(&self.d[i][k])
+                                .then(self.non_basis[j].cmp(&self.non_basis[k]))
+                        })
+                        .unwrap();
+                    self.pivot(i, s);
+                }
+            }
+        }
+        if !self.run(m, aux) {
+            return LpResult::Unbounded;
+        }
+        let mut x = vec![0.0; n];
+        for i in 0..m {
+            if self.basis[i] < n {
+                x[self.basis[i]] = self.d[i][n + 1];
+            }
+        }
+        LpResult::Optimal(self.d[m][n + 1], x)
+    }
+}
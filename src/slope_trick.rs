@@ -0,0 +1,123 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+fn main() {
+    // 広義単調増加にするための Σ |a_i - b_i| の最小値
+    let a = [3, 1, 4, 1, 5, 9, 2, 6];
+    let mut f = SlopeTrick::new();
+    for &x in &a {
+        f.add_abs(x);
+        f.clear_right_slope();
+    }
+    dbg!(f.min());
+
+    let mut f = SlopeTrick::new();
+    f.add_abs(2);
+    f.add_x_minus_a(5);
+    dbg!(
+        f.min(),
+        f.argmin(),
+        (0..8).map(|x| f.eval(x)).collect::<Vec<_>>()
+    );
+    f.sliding_window_min(-1, 3);
+    dbg!(f.argmin(), (0..8).map(|x| f.eval(x)).collect::<Vec<_>>());
+}
+
+/// 傾きが整数の区分線形な凸関数 `f`．はじめは `f(x) = 0`
+///
+/// 傾きが `1` 増える点を，最小値をとる区間の左側は `l`，右側は `r` に持つ．
+#[derive(Clone, Default, Debug)]
+pub struct SlopeTrick {
+    min: i64,
+    l: BinaryHeap<i64>,
+    r: BinaryHeap<Reverse<i64>>,
+    add_l: i64,
+    add_r: i64,
+}
+
+impl SlopeTrick {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    fn top_l(&self) -> Option<i64> {
+        self.l.peek().map(|&x| x + self.add_l)
+    }
+    fn top_r(&self) -> Option<i64> {
+        self.r.peek().map(|&Reverse(x)| x + self.add_r)
+    }
+    fn push_l(&mut self, x: i64) {
+        self.l.push(x - self.add_l);
+    }
+    fn push_r(&mut self, x: i64) {
+        self.r.push(Reverse(x - self.add_r));
+    }
+    fn pop_l(&mut self) -> i64 {
+        self.l.pop().unwrap() + self.add_l
+    }
+    fn pop_r(&mut self) -> i64 {
+        self.r.pop().unwrap().0 + self.add_r
+    }
+    /// `min f`
+    pub fn min(&self) -> i64 {
+        self.min
+    }
+    /// `f` が最小となる区間 `[左端, 右端]`．`None` は無限大
+    pub fn argmin(&self) -> (Option<i64>, Option<i64>) {
+        (self.top_l(), self.top_r())
+    }
+    /// `f(x)`．`O(n)`
+    pub fn eval(&self, x: i64) -> i64 {
+        let l: i64 = self.l.iter().map(|&a| (a + self.add_l - x).max(0)).sum();
+        let r: i64 = self
+            .r
+            .iter()
+            .map(|&Reverse(a)| (x - a - self.add_r).max(0))
+            .sum();
+        self.min + l + r
+    }
+    /// `f(x) += c`
+    pub fn add_const(&mut self, c: i64) {
+        self.min += c;
+    }
+    /// `f(x) += max(0, x - a)`
+    pub fn add_x_minus_a(&mut self, a: i64) {
+        if let Some(l) = self.top_l() {
+            self.min += (l - a).max(0);
+        }
+        self.push_l(a);
+        let x = self.pop_l();
+        self.push_r(x);
+    }
+    /// `f(x) += max(0, a - x)`
+    pub fn add_a_minus_x(&mut self, a: i64) {
+        if let Some(r) = self.top_r() {
+            self.min += (a - r).max(0);
+        }
+        self.push_r(a);
+        let x = self.pop_r();
+        self.push_l(x);
+    }
+    /// `f(x) += |x - a|`
+    pub fn add_abs(&mut self, a: i64) {
+        self.add_x_minus_a(a);
+        self.add_a_minus_x(a);
+    }
+    /// `f(x) ← min_{y >= x} f(y)`．左側の傾きを `0` に
+    pub fn clear_left_slope(&mut self) {
+        self.l.clear();
+    }
+    /// `f(x) ← min_{y <= x} f(y)`．右側の傾きを `0` に
+    pub fn clear_right_slope(&mut self) {
+        self.r.clear();
+    }
+    /// `f(x) ← f(x - a)`
+    pub fn shift(&mut self, a: i64) {
+        self.sliding_window_min(a, a);
+    }
+    /// `f(x) ← min_{x - b <= y <= x - a} f(y)`．`a <= b`
+    pub fn sliding_window_min(&mut self, a: i64, b: i64) {
+        assert!(a <= b);
+        self.add_l += a;
+        self.add_r += b;
+    }
+}
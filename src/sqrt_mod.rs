@@ -0,0 +1,93 @@
+#[allow(dead_code)]
+#[path = "factorize.rs"]
+mod factorize;
+
+use factorize::factorize;
+
+fn main() {
+    dbg!(
+        sqrt_mod(2, 7),
+        sqrt_mod(3, 7),
+        sqrt_mod(0, 7),
+        sqrt_mod(1, 2)
+    );
+    dbg!(sqrt_mod(2, 998244353), sqrt_mod(3, 998244353));
+    let p = 18446744073709551557;
+    let r = sqrt_mod(4, p).unwrap();
+    dbg!(r, p - r);
+    dbg!(
+        primitive_root(2),
+        primitive_root(7),
+        primitive_root(998244353)
+    );
+    dbg!(primitive_root(1_000_000_007), primitive_root(p));
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut a: u64, mut e: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    a %= m;
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mul_mod(res, a, m);
+        }
+        a = mul_mod(a, a, m);
+        e >>= 1;
+    }
+    res
+}
+
+/// 素数 `p` を法とする `a` の平方根のひとつ (Tonelli–Shanks)．なければ `None`
+pub fn sqrt_mod(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if a == 0 || p == 2 {
+        return Some(a);
+    }
+    // Euler の規準
+    if pow_mod(a, (p - 1) / 2, p) != 1 {
+        return None;
+    }
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    // 平方非剰余
+    let z = (2..)
+        .find(|&z| pow_mod(z, (p - 1) / 2, p) == p - 1)
+        .unwrap();
+    // r^2 = a t，c は位数 2^m の元
+    let (mut m, mut c, mut t, mut r) = (
+        s,
+        pow_mod(z, q, p),
+        pow_mod(a, q, p),
+        pow_mod(a, q.div_ceil(2), p),
+    );
+    while t != 1 {
+        // t^{2^i} = 1 となる最小の i
+        let mut i = 0;
+        let mut tt = t;
+        while tt != 1 {
+            tt = mul_mod(tt, tt, p);
+            i += 1;
+        }
+        let b = pow_mod(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
+    }
+    Some(r)
+}
+
+/// 素数 `p` の原始根のうち最小のもの
+pub fn primitive_root(p: u64) -> u64 {
+    if p == 2 {
+        return 1;
+    }
+    let mut factors = factorize(p - 1);
+    factors.dedup();
+    (2..)
+        .find(|&g| factors.iter().all(|&q| pow_mod(g, (p - 1) / q, p) != 1))
+        .unwrap()
+}
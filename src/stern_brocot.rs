@@ -0,0 +1,159 @@
+fn main() {
+    dbg!(
+        continued_fraction(415, 93),
+        convergents(&continued_fraction(415, 93))
+    );
+    // π ≈ 3.14159265358979
+    let (p, q) = (314_159_265_358_979, 100_000_000_000_000);
+    dbg!(
+        best_bounds(p, q, 1000),
+        closest(p, q, 1000),
+        closest(p, q, 100)
+    );
+
+    dbg!(encode_path(5, 7), decode_path(&encode_path(5, 7)));
+    dbg!(
+        lca((5, 7), (3, 4)),
+        ancestor(2, (5, 7)),
+        ancestor(10, (5, 7))
+    );
+    dbg!(subtree_range((5, 7)), subtree_range((1, 1)));
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// `p / q = a0 + 1 / (a1 + 1 / (a2 + ...))` の `[a0, a1, ...]`．`q >= 1`
+pub fn continued_fraction(mut p: u64, mut q: u64) -> Vec<u64> {
+    assert!(q >= 1);
+    let mut res = vec![];
+    while q != 0 {
+        res.push(p / q);
+        (p, q) = (q, p % q);
+    }
+    res
+}
+
+/// 連分数の各段で打ち切った分数 (近似分数) を `(分子, 分母)` で
+pub fn convergents(cf: &[u64]) -> Vec<(u64, u64)> {
+    // p_k = a_k p_{k-1} + p_{k-2}
+    let (mut p0, mut q0, mut p1, mut q1) = (0, 1, 1, 0);
+    cf.iter()
+        .map(|&a| {
+            (p0, q0, p1, q1) = (p1, q1, a * p1 + p0, a * q1 + q0);
+            (p1, q1)
+        })
+        .collect()
+}
+
+/// 分母が `n` 以下の分数で，`p / q` 以下で最大のものと，`p / q` 以上で最小のもの．`n >= 1`, `q >= 1`
+pub fn best_bounds(p: u64, q: u64, n: u64) -> ((u64, u64), (u64, u64)) {
+    assert!(n >= 1 && q >= 1);
+    let g = gcd(p, q);
+    let (p, q) = (p / g, q / g);
+    if q <= n {
+        return ((p, q), (p, q));
+    }
+    let (p, q, n) = (p as u128, q as u128, n as u128);
+    let (mut lo, mut hi) = ((0u128, 1u128), (1u128, 0u128));
+    loop {
+        // lo ← lo + k hi (lo + k hi <= p / q かつ分母が n 以下の範囲で k を最大に)
+        let k_x = (p * lo.1 - q * lo.0) / (q * hi.0 - p * hi.1);
+        let k_n = (n - lo.1).checked_div(hi.1).unwrap_or(k_x);
+        let k = k_x.min(k_n);
+        lo = (lo.0 + k * hi.0, lo.1 + k * hi.1);
+        // hi ← hi + k lo
+        let k_x = (q * hi.0 - p * hi.1) / (p * lo.1 - q * lo.0);
+        let k_n = (n - hi.1) / lo.1;
+        let k2 = k_x.min(k_n);
+        hi = (hi.0 + k2 * lo.0, hi.1 + k2 * lo.1);
+        if k == 0 && k2 == 0 {
+            break;
+        }
+    }
+    ((lo.0 as u64, lo.1 as u64), (hi.0 as u64, hi.1 as u64))
+}
+
+/// 分母が `n` 以下の分数で `p / q` に最も近いもの．同じ距離なら小さいほう
+pub fn closest(p: u64, q: u64, n: u64) -> (u64, u64) {
+    let (lo, hi) = best_bounds(p, q, n);
+    // p / q - lo <= hi - p / q
+    let (p, q) = (p as u128, q as u128);
+    let d_lo = (p * lo.1 as u128 - q * lo.0 as u128) * hi.1 as u128;
+    let d_hi = (q * hi.0 as u128 - p * hi.1 as u128) * lo.1 as u128;
+    if d_lo <= d_hi {
+        lo
+    } else {
+        hi
+    }
+}
+
+/// Stern–Brocot 木で根 `1 / 1` から `p / q` への経路を，`('L' または 'R', 回数)` の列で．`p, q >= 1`
+pub fn encode_path(p: u64, q: u64) -> Vec<(char, u64)> {
+    assert!(p >= 1 && q >= 1);
+    let g = gcd(p, q);
+    let mut cf = continued_fraction(p / g, q / g);
+    *cf.last_mut().unwrap() -= 1;
+    cf.into_iter()
+        .zip(['R', 'L'].into_iter().cycle())
+        .filter(|&(k, _)| k > 0)
+        .map(|(k, c)| (c, k))
+        .collect()
+}
+
+/// 経路をたどったときの `(左端, 右端)`．ノードは `左端 + 右端` (分子，分母それぞれの和)
+fn descend(path: &[(char, u64)]) -> ((u64, u64), (u64, u64)) {
+    let (mut lo, mut hi) = ((0, 1), (1, 0));
+    for &(c, k) in path {
+        if c == 'R' {
+            lo = (lo.0 + k * hi.0, lo.1 + k * hi.1);
+        } else {
+            hi = (hi.0 + k * lo.0, hi.1 + k * lo.1);
+        }
+    }
+    (lo, hi)
+}
+
+/// `encode_path` の逆
+pub fn decode_path(path: &[(char, u64)]) -> (u64, u64) {
+    let (lo, hi) = descend(path);
+    (lo.0 + hi.0, lo.1 + hi.1)
+}
+
+/// 最小共通祖先
+pub fn lca(a: (u64, u64), b: (u64, u64)) -> (u64, u64) {
+    let (pa, pb) = (encode_path(a.0, a.1), encode_path(b.0, b.1));
+    let mut path = vec![];
+    for (&(c, k), &(d, l)) in pa.iter().zip(&pb) {
+        if c != d {
+            break;
+        }
+        path.push((c, k.min(l)));
+        if k != l {
+            break;
+        }
+    }
+    decode_path(&path)
+}
+
+/// 深さ `k` (根は `0`) の祖先．`k` が深さより大きければ `None`
+pub fn ancestor(mut k: u64, a: (u64, u64)) -> Option<(u64, u64)> {
+    let mut path = vec![];
+    for (c, l) in encode_path(a.0, a.1) {
+        if k == 0 {
+            break;
+        }
+        path.push((c, l.min(k)));
+        k -= l.min(k);
+    }
+    (k == 0).then(|| decode_path(&path))
+}
+
+/// 部分木に含まれる分数全体の区間 (両端は含まない)．右端は `1 / 0` (無限大) でありうる
+pub fn subtree_range(a: (u64, u64)) -> ((u64, u64), (u64, u64)) {
+    descend(&encode_path(a.0, a.1))
+}
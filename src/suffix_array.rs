@@ -0,0 +1,320 @@
+use std::{cmp::Ordering, ops::Range};
+
+fn main() {
+    let s = b"abracadabra";
+    dbg!(suffix_array_u8(s));
+    dbg!(lcp_array(s, &suffix_array_u8(s)));
+    dbg!(suffix_array(&[3, 1, 4, 1, 5, 9, 2, 6]));
+    dbg!(sa_is(&[2, 0, 1, 0, 2], 2));
+
+    let sa = SuffixArray::new(s.to_vec());
+    dbg!(sa.sa(), sa.rank(), sa.lcp());
+    dbg!(sa.lower_bound(b"abr"), sa.find(b"abr"), sa.count(b"a"));
+    dbg!(sa.count(b"cab"), sa.occurrences(b"bra"));
+
+    let sa = SuffixArray::new(vec![1_000_000_007u64, 3, 1_000_000_007, 3]);
+    dbg!(sa.occurrences(&[1_000_000_007, 3]));
+
+    let sa = SuffixArray::new(s.to_vec());
+    let q = sa.lcp_query();
+    dbg!(q.lcp(0, 7), q.lcp(3, 5), q.lcp(2, 2), q.compare(0, 7));
+    dbg!(q.eq(0..4, 7..11), q.eq(1..3, 8..10), q.eq(0..2, 3..5));
+}
+
+/// `s` の各要素が `0..=upper` に入っているときの SA-IS
+pub fn sa_is(s: &[usize], upper: usize) -> Vec<usize> {
+    const NONE: usize = usize::MAX;
+    let n = s.len();
+    match n {
+        0 => return vec![],
+        1 => return vec![0],
+        2 => return if s[0] < s[1] { vec![0, 1] } else { vec![1, 0] },
+        _ => {}
+    }
+    if n < 10 {
+        let mut sa: Vec<usize> = (0..n).collect();
+        sa.sort_by(|&a, &b| s[a..].cmp(&s[b..]));
+        return sa;
+    }
+    // ls[i]: s[i..] が s[i + 1..] より小さい (S 型)
+    let mut ls = vec![false; n];
+    for i in (0..n - 1).rev() {
+        ls[i] = if s[i] == s[i + 1] {
+            ls[i + 1]
+        } else {
+            s[i] < s[i + 1]
+        };
+    }
+    let mut sum_l = vec![0; upper + 1];
+    let mut sum_s = vec![0; upper + 1];
+    for i in 0..n {
+        if !ls[i] {
+            sum_s[s[i]] += 1;
+        } else {
+            sum_l[s[i] + 1] += 1;
+        }
+    }
+    for i in 0..=upper {
+        sum_s[i] += sum_l[i];
+        if i < upper {
+            sum_l[i + 1] += sum_s[i];
+        }
+    }
+    let induce = |sa: &mut Vec<usize>, lms: &[usize]| {
+        sa.iter_mut().for_each(|x| *x = NONE);
+        let mut buf = sum_s.clone();
+        for &d in lms {
+            if d == n {
+                continue;
+            }
+            sa[buf[s[d]]] = d;
+            buf[s[d]] += 1;
+        }
+        let mut buf = sum_l.clone();
+        sa[buf[s[n - 1]]] = n - 1;
+        buf[s[n - 1]] += 1;
+        for i in 0..n {
+            let v = sa[i];
+            if v != NONE && v >= 1 && !ls[v - 1] {
+                sa[buf[s[v - 1]]] = v - 1;
+                buf[s[v - 1]] += 1;
+            }
+        }
+        let mut buf = sum_l.clone();
+        for i in (0..n).rev() {
+            let v = sa[i];
+            if v != NONE && v >= 1 && ls[v - 1] {
+                buf[s[v - 1] + 1] -= 1;
+                sa[buf[s[v - 1] + 1]] = v - 1;
+            }
+        }
+    };
+
+    let mut lms_map = vec![NONE; n + 1];
+    let mut lms = vec![];
+    for i in 1..n {
+        if !ls[i - 1] && ls[i] {
+            lms_map[i] = lms.len();
+            lms.push(i);
+        }
+    }
+    let m = lms.len();
+    let mut sa = vec![NONE; n];
+    induce(&mut sa, &lms);
+    if m != 0 {
+        let mut sorted_lms: Vec<usize> =
+            sa.iter().copied().filter(|&v| lms_map[v] != NONE).collect();
+        let mut rec_s = vec![0; m];
+        let mut rec_upper = 0;
+        rec_s[lms_map[sorted_lms[0]]] = 0;
+        for i in 1..m {
+            let mut l = sorted_lms[i - 1];
+            let mut r = sorted_lms[i];
+            let end_l = if lms_map[l] + 1 < m {
+                lms[lms_map[l] + 1]
+            } else {
+                n
+            };
+            let end_r = if lms_map[r] + 1 < m {
+                lms[lms_map[r] + 1]
+            } else {
+                n
+            };
+            let mut same = true;
+            if end_l - l != end_r - r {
+                same = false;
+            } else {
+                while l < end_l && s[l] == s[r] {
+                    l += 1;
+                    r += 1;
+                }
+                if l == n || s[l] != s[r] {
+                    same = false;
+                }
+            }
+            if !same {
+                rec_upper += 1;
+            }
+            rec_s[lms_map[sorted_lms[i]]] = rec_upper;
+        }
+        let rec_sa = sa_is(&rec_s, rec_upper);
+        for i in 0..m {
+            sorted_lms[i] = lms[rec_sa[i]];
+        }
+        induce(&mut sa, &sorted_lms);
+    }
+    sa
+}
+
+pub fn suffix_array_u8(s: &[u8]) -> Vec<usize> {
+    let s: Vec<usize> = s.iter().map(|&c| c as usize).collect();
+    sa_is(&s, 255)
+}
+
+/// 座標圧縮してから SA-IS
+pub fn suffix_array<T: Ord>(s: &[T]) -> Vec<usize> {
+    let mut idx: Vec<usize> = (0..s.len()).collect();
+    idx.sort_by(|&a, &b| s[a].cmp(&s[b]));
+    let mut t = vec![0; s.len()];
+    let mut now = 0;
+    for i in 0..idx.len() {
+        if i > 0 && s[idx[i - 1]] != s[idx[i]] {
+            now += 1;
+        }
+        t[idx[i]] = now;
+    }
+    sa_is(&t, now)
+}
+
+/// Kasai のアルゴリズム．`lcp[i]`: `s[sa[i]..]` と `s[sa[i + 1]..]` の最長共通接頭辞の長さ
+pub fn lcp_array<T: Eq>(s: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+    let mut lcp = vec![0; n - 1];
+    let mut h: usize = 0;
+    for i in 0..n {
+        h = h.saturating_sub(1);
+        if rank[i] == 0 {
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while j + h < n && i + h < n && s[j + h] == s[i + h] {
+            h += 1;
+        }
+        lcp[rank[i] - 1] = h;
+    }
+    lcp
+}
+
+pub struct SuffixArray<T> {
+    s: Vec<T>,
+    sa: Vec<usize>,
+    rank: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<T: Ord> SuffixArray<T> {
+    pub fn new(s: Vec<T>) -> Self {
+        let sa = suffix_array(&s);
+        let mut rank = vec![0; s.len()];
+        for (i, &p) in sa.iter().enumerate() {
+            rank[p] = i;
+        }
+        let lcp = lcp_array(&s, &sa);
+        Self { s, sa, rank, lcp }
+    }
+    pub fn len(&self) -> usize {
+        self.s.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+    pub fn as_slice(&self) -> &[T] {
+        &self.s
+    }
+    pub fn sa(&self) -> &[usize] {
+        &self.sa
+    }
+    /// `rank[i]`: `s[i..]` が何番目に小さい接尾辞か
+    pub fn rank(&self) -> &[usize] {
+        &self.rank
+    }
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+    /// `s[sa[i]..] >= pattern` となる最小の `i`
+    pub fn lower_bound(&self, pattern: &[T]) -> usize {
+        self.sa.partition_point(|&p| &self.s[p..] < pattern)
+    }
+    /// `pattern` で始まる接尾辞の，`sa` 上での区間
+    pub fn find(&self, pattern: &[T]) -> Range<usize> {
+        let start = self.lower_bound(pattern);
+        let end = self.sa.partition_point(|&p| {
+            let suffix = &self.s[p..];
+            &suffix[..suffix.len().min(pattern.len())] <= pattern
+        });
+        start..end
+    }
+    pub fn count(&self, pattern: &[T]) -> usize {
+        self.find(pattern).len()
+    }
+    /// `pattern` の出現位置 (始点) を昇順に
+    pub fn occurrences(&self, pattern: &[T]) -> Vec<usize> {
+        let mut res = self.sa[self.find(pattern)].to_vec();
+        res.sort_unstable();
+        res
+    }
+    pub fn lcp_query(&self) -> LcpQuery {
+        LcpQuery::new(&self.sa, &self.lcp)
+    }
+}
+
+/// 任意の2つの接尾辞の最長共通接頭辞の長さを `O(1)` で求める
+pub struct LcpQuery {
+    rank: Vec<usize>,
+    // table[k][i]: min(lcp[i..i + 2^k])
+    table: Vec<Vec<usize>>,
+}
+
+impl LcpQuery {
+    pub fn new(sa: &[usize], lcp: &[usize]) -> Self {
+        let n = sa.len();
+        let mut rank = vec![0; n];
+        for (i, &p) in sa.iter().enumerate() {
+            rank[p] = i;
+        }
+        let mut table = vec![lcp.to_vec()];
+        let mut k = 1;
+        while 2 * k <= lcp.len() {
+            let prev = table.last().unwrap();
+            let next = (0..=lcp.len() - 2 * k)
+                .map(|i| prev[i].min(prev[i + k]))
+                .collect();
+            table.push(next);
+            k *= 2;
+        }
+        Self { rank, table }
+    }
+    pub fn len(&self) -> usize {
+        self.rank.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.rank.is_empty()
+    }
+    /// `s[i..]` と `s[j..]` の最長共通接頭辞の長さ．`i`, `j` は `n` でもよい
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        let n = self.len();
+        if i == n || j == n {
+            return 0;
+        }
+        if i == j {
+            return n - i;
+        }
+        let (l, r) = {
+            let (a, b) = (self.rank[i], self.rank[j]);
+            (a.min(b), a.max(b))
+        };
+        let k = (r - l).ilog2() as usize;
+        self.table[k][l].min(self.table[k][r - (1 << k)])
+    }
+    /// `s[i..]` と `s[j..]` の比較
+    pub fn compare(&self, i: usize, j: usize) -> Ordering {
+        let n = self.len();
+        match (i == n, j == n) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.rank[i].cmp(&self.rank[j]),
+        }
+    }
+    /// `s[a] == s[b]`
+    pub fn eq(&self, a: Range<usize>, b: Range<usize>) -> bool {
+        a.len() == b.len() && (a.is_empty() || self.lcp(a.start, b.start) >= a.len())
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+
+fn main() {
+    let mut sam = SuffixAutomaton::new();
+    for c in "abcbc".chars() {
+        sam.push(c);
+    }
+    dbg!(sam.states().len(), sam.last());
+    dbg!(sam.count_distinct_substrings());
+    dbg!(sam.occurrences());
+    dbg!(sam.contains(&['c', 'b']), sam.contains(&['c', 'a']));
+    dbg!(sam.count(&['b', 'c']), sam.count(&['c']));
+
+    let sam = SuffixAutomaton::from(&b"aaaa"[..]);
+    dbg!(sam.count_distinct_substrings());
+    for (i, st) in sam.states().iter().enumerate() {
+        dbg!(i, st.len, st.link, &st.next);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct State<T> {
+    /// この状態に対応する部分文字列のうち最長のものの長さ
+    pub len: usize,
+    /// suffix link．根は `None`
+    pub link: Option<usize>,
+    pub next: BTreeMap<T, usize>,
+    /// 複製によって作られた状態か
+    pub is_clone: bool,
+}
+
+pub struct SuffixAutomaton<T> {
+    states: Vec<State<T>>,
+    last: usize,
+}
+
+impl<T: Ord + Clone> Default for SuffixAutomaton<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> SuffixAutomaton<T> {
+    pub fn new() -> Self {
+        Self {
+            states: vec![State {
+                len: 0,
+                link: None,
+                next: BTreeMap::new(),
+                is_clone: false,
+            }],
+            last: 0,
+        }
+    }
+    pub fn states(&self) -> &[State<T>] {
+        &self.states
+    }
+    /// 文字列全体に対応する状態
+    pub fn last(&self) -> usize {
+        self.last
+    }
+    pub fn push(&mut self, c: T) {
+        let cur = self.states.len();
+        self.states.push(State {
+            len: self.states[self.last].len + 1,
+            link: None,
+            next: BTreeMap::new(),
+            is_clone: false,
+        });
+        let mut p = Some(self.last);
+        while let Some(v) = p {
+            if self.states[v].next.contains_key(&c) {
+                break;
+            }
+            self.states[v].next.insert(c.clone(), cur);
+            p = self.states[v].link;
+        }
+        self.last = cur;
+        let p = match p {
+            Some(p) => p,
+            None => {
+                self.states[cur].link = Some(0);
+                return;
+            }
+        };
+        let q = self.states[p].next[&c];
+        if self.states[p].len + 1 == self.states[q].len {
+            self.states[cur].link = Some(q);
+            return;
+        }
+        let clone = self.states.len();
+        let mut state = self.states[q].clone();
+        state.len = self.states[p].len + 1;
+        state.is_clone = true;
+        self.states.push(state);
+        let mut p = Some(p);
+        while let Some(v) = p {
+            match self.states[v].next.get_mut(&c) {
+                Some(to) if *to == q => *to = clone,
+                _ => break,
+            }
+            p = self.states[v].link;
+        }
+        self.states[q].link = Some(clone);
+        self.states[cur].link = Some(clone);
+    }
+    /// 状態 `v` から `s` をたどった先
+    pub fn walk(&self, mut v: usize, s: &[T]) -> Option<usize> {
+        for c in s {
+            v = *self.states[v].next.get(c)?;
+        }
+        Some(v)
+    }
+    pub fn contains(&self, s: &[T]) -> bool {
+        self.walk(0, s).is_some()
+    }
+    /// 空でない相異なる部分文字列の個数
+    pub fn count_distinct_substrings(&self) -> u64 {
+        self.states
+            .iter()
+            .skip(1)
+            .map(|st| (st.len - self.states[st.link.unwrap()].len) as u64)
+            .sum()
+    }
+    /// 各状態に対応する部分文字列の出現回数
+    pub fn occurrences(&self) -> Vec<usize> {
+        let mut cnt: Vec<usize> = self
+            .states
+            .iter()
+            .map(|st| if st.is_clone { 0 } else { 1 })
+            .collect();
+        let mut order: Vec<usize> = (1..self.states.len()).collect();
+        order.sort_unstable_by_key(|&v| std::cmp::Reverse(self.states[v].len));
+        for v in order {
+            let link = self.states[v].link.unwrap();
+            cnt[link] += cnt[v];
+        }
+        // 空文字列はすべての位置に現れる
+        cnt[0] = self.states[self.last].len + 1;
+        cnt
+    }
+    /// `s` の出現回数．何度も呼ぶなら `occurrences` を一度求めておくほうがよい．
+    pub fn count(&self, s: &[T]) -> usize {
+        match self.walk(0, s) {
+            Some(v) => self.occurrences()[v],
+            None => 0,
+        }
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for SuffixAutomaton<T> {
+    fn from(s: &[T]) -> Self {
+        let mut sam = Self::new();
+        for c in s {
+            sam.push(c.clone());
+        }
+        sam
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+
+fn main() {
+    let mut trie = Trie::<u8, MaxU64>::new();
+    trie.insert(b"apple", 3);
+    trie.insert(b"app", 5);
+    trie.insert(b"apply", 2);
+    trie.insert(b"banana", 7);
+    dbg!(
+        trie.len(),
+        trie.count_prefix(b"app"),
+        trie.prod_prefix(b"appl")
+    );
+    dbg!(trie.insert(b"app", 1), trie.prod_prefix(b"app"));
+    dbg!(trie.remove(b"apple"), trie.remove(b"apple"));
+    dbg!(
+        trie.get(b"apply"),
+        trie.count_prefix(b"app"),
+        trie.prod_prefix(b"")
+    );
+
+    // キーの集合としてだけ使う
+    let mut trie = Trie::<char, NoAggregate>::new();
+    for s in ["ねこ", "ねずみ", "いぬ"] {
+        trie.insert(&s.chars().collect::<Vec<_>>(), ());
+    }
+    let v = trie.find(&['ね']).unwrap();
+    dbg!(trie.node_count(v), trie.child(v, &'こ'), trie.num_nodes());
+}
+
+pub trait TrieType {
+    type Item: Clone;
+    fn id() -> Self::Item;
+    fn prod(a: &Self::Item, b: &Self::Item) -> Self::Item;
+}
+
+pub enum NoAggregate {}
+
+impl TrieType for NoAggregate {
+    type Item = ();
+    fn id() {}
+    fn prod(_: &(), _: &()) {}
+}
+
+pub enum MaxU64 {}
+
+impl TrieType for MaxU64 {
+    type Item = u64;
+    fn id() -> u64 {
+        0
+    }
+    fn prod(a: &u64, b: &u64) -> u64 {
+        *a.max(b)
+    }
+}
+
+struct Node<K, T: TrieType> {
+    children: BTreeMap<K, usize>,
+    value: Option<T::Item>,
+    // 部分木に含まれるキーの個数と，その値の (キーの辞書順の) 積
+    count: usize,
+    prod: T::Item,
+}
+
+/// キーが `[K]`，値が `T::Item` の map
+pub struct Trie<K, T: TrieType> {
+    nodes: Vec<Node<K, T>>,
+}
+
+impl<K: Ord + Clone, T: TrieType> Default for Trie<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone, T: TrieType> Trie<K, T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Self::new_node()],
+        }
+    }
+    fn new_node() -> Node<K, T> {
+        Node {
+            children: BTreeMap::new(),
+            value: None,
+            count: 0,
+            prod: T::id(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// ノード数．削除してもノードは減らない．
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+    /// 根は `0`
+    pub fn child(&self, v: usize, c: &K) -> Option<usize> {
+        self.nodes[v].children.get(c).copied()
+    }
+    pub fn children(&self, v: usize) -> impl Iterator<Item = (&K, usize)> {
+        self.nodes[v].children.iter().map(|(c, &to)| (c, to))
+    }
+    /// ノード `v` の部分木に含まれるキーの個数
+    pub fn node_count(&self, v: usize) -> usize {
+        self.nodes[v].count
+    }
+    /// ノード `v` の部分木に含まれるキーの値の積
+    pub fn node_prod(&self, v: usize) -> &T::Item {
+        &self.nodes[v].prod
+    }
+    /// ノード `v` で終わるキーの値
+    pub fn node_value(&self, v: usize) -> Option<&T::Item> {
+        self.nodes[v].value.as_ref()
+    }
+    /// `prefix` に対応するノード
+    pub fn find(&self, prefix: &[K]) -> Option<usize> {
+        let mut v = 0;
+        for c in prefix {
+            v = self.child(v, c)?;
+        }
+        Some(v)
+    }
+    pub fn get(&self, key: &[K]) -> Option<&T::Item> {
+        self.node_value(self.find(key)?)
+    }
+    /// `prefix` で始まるキーの個数
+    pub fn count_prefix(&self, prefix: &[K]) -> usize {
+        self.find(prefix).map_or(0, |v| self.nodes[v].count)
+    }
+    /// `prefix` で始まるキーの値の積
+    pub fn prod_prefix(&self, prefix: &[K]) -> T::Item {
+        self.find(prefix)
+            .map_or_else(T::id, |v| self.nodes[v].prod.clone())
+    }
+    /// 以前の値を返す
+    pub fn insert(&mut self, key: &[K], value: T::Item) -> Option<T::Item> {
+        let mut path = vec![0];
+        for c in key {
+            let v = *path.last().unwrap();
+            let to = match self.child(v, c) {
+                Some(to) => to,
+                None => {
+                    let to = self.nodes.len();
+                    self.nodes.push(Self::new_node());
+                    self.nodes[v].children.insert(c.clone(), to);
+                    to
+                }
+            };
+            path.push(to);
+        }
+        let old = self.nodes[*path.last().unwrap()].value.replace(value);
+        self.update(&path);
+        old
+    }
+    pub fn remove(&mut self, key: &[K]) -> Option<T::Item> {
+        let mut path = vec![0];
+        for c in key {
+            path.push(self.child(*path.last().unwrap(), c)?);
+        }
+        let old = self.nodes[*path.last().unwrap()].value.take()?;
+        self.update(&path);
+        Some(old)
+    }
+    fn update(&mut self, path: &[usize]) {
+        for &v in path.iter().rev() {
+            let node = &self.nodes[v];
+            let mut count = if node.value.is_some() { 1 } else { 0 };
+            let mut prod = node.value.clone().unwrap_or_else(T::id);
+            for &to in node.children.values() {
+                count += self.nodes[to].count;
+                prod = T::prod(&prod, &self.nodes[to].prod);
+            }
+            let node = &mut self.nodes[v];
+            node.count = count;
+            node.prod = prod;
+        }
+    }
+}
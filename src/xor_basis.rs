@@ -0,0 +1,116 @@
+fn main() {
+    let mut basis = XorBasis::new();
+    for x in [0b1011, 0b0110, 0b1101, 0b0011] {
+        dbg!(x, basis.insert(x));
+    }
+    dbg!(basis.rank(), basis.contains(0b1000), basis.contains(0b0100));
+    dbg!(basis.max_xor(), basis.max_xor_with(0b0100));
+    dbg!((0..1 << basis.rank())
+        .map(|k| basis.kth(k).unwrap())
+        .collect::<Vec<_>>());
+    dbg!(basis.kth(1 << basis.rank()));
+
+    let mut other = XorBasis::new();
+    other.insert(0b10000);
+    basis.merge(&other);
+    dbg!(
+        basis.rank(),
+        basis.max_xor(),
+        basis.iter().collect::<Vec<_>>()
+    );
+}
+
+/// `u64` を `F_2` 上のベクトルとみたときの線形空間の基底
+///
+/// 常に簡約した形 (ある基底の最上位ビットは，他の基底では `0`) で持つ．
+#[derive(Clone, Debug)]
+pub struct XorBasis {
+    /// `basis[i]`: 最上位ビットが `i` の基底．なければ `0`
+    basis: [u64; 64],
+    rank: usize,
+}
+
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XorBasis {
+    pub fn new() -> Self {
+        Self {
+            basis: [0; 64],
+            rank: 0,
+        }
+    }
+    /// 基底の個数．張る空間の要素数は `2^rank`
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+    /// `x` を基底で消せるだけ消したもの
+    fn reduce(&self, mut x: u64) -> u64 {
+        for i in (0..64).rev() {
+            if x >> i & 1 == 1 {
+                x ^= self.basis[i];
+            }
+        }
+        x
+    }
+    /// `x` を追加する．基底が増えたら `true`
+    pub fn insert(&mut self, x: u64) -> bool {
+        let x = self.reduce(x);
+        if x == 0 {
+            return false;
+        }
+        let top = 63 - x.leading_zeros() as usize;
+        // 簡約した形を保つ．x の下のビットは reduce で消えている
+        for b in &mut self.basis[top + 1..] {
+            if *b >> top & 1 == 1 {
+                *b ^= x;
+            }
+        }
+        self.basis[top] = x;
+        self.rank += 1;
+        true
+    }
+    /// `x` がいくつかの要素の XOR で表せるか
+    pub fn contains(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+    /// いくつかの要素の XOR の最大値 (空の XOR の `0` も含む)
+    pub fn max_xor(&self) -> u64 {
+        self.max_xor_with(0)
+    }
+    /// いくつかの要素の XOR と `x` の XOR の最大値
+    pub fn max_xor_with(&self, mut x: u64) -> u64 {
+        for i in (0..64).rev() {
+            if x >> i & 1 == 0 {
+                x ^= self.basis[i];
+            }
+        }
+        x
+    }
+    /// いくつかの要素の XOR で表せる値のうち，小さいほうから `k` 番目 (`0` 番目は `0`)．`k >= 2^rank` なら `None`
+    pub fn kth(&self, k: u64) -> Option<u64> {
+        if self.rank < 64 && k >> self.rank != 0 {
+            return None;
+        }
+        // 簡約した基底を昇順に並べると，k の各ビットで使うかどうかが決まる
+        Some(
+            self.iter()
+                .enumerate()
+                .filter(|&(j, _)| k >> j & 1 == 1)
+                .fold(0, |acc, (_, b)| acc ^ b),
+        )
+    }
+    /// 基底を最上位ビットの昇順に
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        self.basis.iter().copied().filter(|&b| b != 0)
+    }
+    /// `other` の張る空間との和
+    pub fn merge(&mut self, other: &Self) {
+        for b in other.iter() {
+            self.insert(b);
+        }
+    }
+}
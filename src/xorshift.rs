@@ -0,0 +1,174 @@
+use std::ops::{Range, RangeInclusive};
+
+fn main() {
+    let mut rng = XorShift64::new(42);
+    dbg!(
+        rng.next_u64(),
+        rng.gen_range(0..10usize),
+        rng.gen_range(-5..=5i64)
+    );
+    dbg!(rng.gen_f64(), rng.gen_bool(0.5));
+    let mut a: Vec<u32> = (0..10).collect();
+    rng.shuffle(&mut a);
+    dbg!(&a, rng.choose(&a));
+
+    let weights = [1.0, 0.0, 3.0, 6.0];
+    let mut cnt = [0; 4];
+    for _ in 0..10000 {
+        cnt[rng.weighted_index(&weights)] += 1;
+    }
+    dbg!(cnt);
+    let alias = AliasTable::new(&weights);
+    let mut cnt = [0; 4];
+    for _ in 0..10000 {
+        cnt[alias.sample(&mut rng)] += 1;
+    }
+    dbg!(cnt);
+    // 実行ごとに変わる
+    dbg!(XorShift64::from_time().gen_range(0..100u32));
+}
+
+/// xorshift による 64 bit の擬似乱数
+#[derive(Clone, Debug)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// 状態が `0` にならないように，`seed` を splitmix64 で混ぜてから使う
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        Self { state: z.max(1) }
+    }
+    /// 現在時刻を seed に
+    pub fn from_time() -> Self {
+        let t = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+        Self::new(t.as_nanos() as u64)
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+    /// `[0, n)` の一様乱数．`n >= 1`．偏りは `n / 2^64` 程度
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n >= 1);
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+    /// `rng.gen_range(0..n)` や `rng.gen_range(-5..=5)` のように
+    pub fn gen_range<T, R: SampleRange<T>>(&mut self, range: R) -> T {
+        range.sample(self)
+    }
+    /// `[0, 1)` の一様乱数
+    pub fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    /// 確率 `p` で `true`
+    pub fn gen_bool(&mut self, p: f64) -> bool {
+        self.gen_f64() < p
+    }
+    /// Fisher–Yates
+    pub fn shuffle<T>(&mut self, a: &mut [T]) {
+        for i in (1..a.len()).rev() {
+            a.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+    /// 一様に 1 つ選ぶ．空なら `None`
+    pub fn choose<'a, T>(&mut self, a: &'a [T]) -> Option<&'a T> {
+        (!a.is_empty()).then(|| &a[self.below(a.len() as u64) as usize])
+    }
+    /// 確率 `weights[i] / Σ weights` で `i` を選ぶ．`O(n)`．重みは非負で，和は正
+    pub fn weighted_index(&mut self, weights: &[f64]) -> usize {
+        let sum: f64 = weights.iter().sum();
+        assert!(sum > 0.0);
+        let mut x = self.gen_f64() * sum;
+        for (i, &w) in weights.iter().enumerate() {
+            if x < w {
+                return i;
+            }
+            x -= w;
+        }
+        // 誤差で最後まで来たら，重みが正の最後のもの
+        weights.iter().rposition(|&w| w > 0.0).unwrap()
+    }
+}
+
+/// `gen_range` に渡せる範囲
+pub trait SampleRange<T> {
+    fn sample(self, rng: &mut XorShift64) -> T;
+}
+
+macro_rules! impl_sample_range {
+    ($($t:ty)*) => {$(
+        impl SampleRange<$t> for Range<$t> {
+            fn sample(self, rng: &mut XorShift64) -> $t {
+                assert!(self.start < self.end);
+                let len = self.end.abs_diff(self.start) as u64;
+                self.start.wrapping_add(rng.below(len) as $t)
+            }
+        }
+        impl SampleRange<$t> for RangeInclusive<$t> {
+            fn sample(self, rng: &mut XorShift64) -> $t {
+                let (start, end) = self.into_inner();
+                assert!(start <= end);
+                // 幅が 2^64 ならそのまま
+                match (end.abs_diff(start) as u64).checked_add(1) {
+                    Some(len) => start.wrapping_add(rng.below(len) as $t),
+                    None => rng.next_u64() as $t,
+                }
+            }
+        }
+    )*};
+}
+
+impl_sample_range!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+
+/// Walker の alias 法．確率 `weights[i] / Σ weights` で `i` を選ぶのを `O(1)` で
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    /// `i` を一様に選んだあと，確率 `prob[i]` で `i`，そうでなければ `alias[i]`
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// 重みは非負で，和は正．`O(n)`
+    pub fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        let sum: f64 = weights.iter().sum();
+        assert!(sum > 0.0);
+        // 平均が 1 になるように
+        let mut prob: Vec<f64> = weights.iter().map(|&w| w * n as f64 / sum).collect();
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| prob[i] < 1.0);
+        // 1 に満たない i の残りを，1 を超える j で埋める
+        while let (Some(&i), Some(&j)) = (small.last(), large.last()) {
+            small.pop();
+            alias[i] = j;
+            prob[j] -= 1.0 - prob[i];
+            if prob[j] < 1.0 {
+                large.pop();
+                small.push(j);
+            }
+        }
+        // 残ったものは誤差を除いて 1
+        for i in small.into_iter().chain(large) {
+            prob[i] = 1.0;
+        }
+        Self { prob, alias }
+    }
+    pub fn sample(&self, rng: &mut XorShift64) -> usize {
+        let i = rng.below(self.prob.len() as u64) as usize;
+        if rng.gen_f64() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
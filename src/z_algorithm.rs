@@ -0,0 +1,50 @@
+fn main() {
+    dbg!(z_algorithm(b"aaabaaaab"));
+    dbg!(z_algorithm(&"あいあいあ".chars().collect::<Vec<_>>()));
+    dbg!(z_algorithm(&[3, 1, 3, 1, 3, 3]));
+    dbg!(z_algorithm::<u8>(&[]));
+
+    // 愚直な計算と比べる
+    let mut x: u64 = 88172645463325252;
+    for _ in 0..1000 {
+        let s: Vec<u64> = (0..x % 20)
+            .map(|_| {
+                x ^= x << 7;
+                x ^= x >> 9;
+                x % 3
+            })
+            .collect();
+        assert_eq!(z_algorithm(&s), z_algorithm_naive(&s));
+    }
+}
+
+/// `z[i]`: `s` と `s[i..]` の最長共通接頭辞の長さ
+pub fn z_algorithm<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return vec![];
+    }
+    let mut z = vec![0; n];
+    z[0] = n;
+    // [l, r) が s[0..r - l] と一致している
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = z[i - l].min(r - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+pub fn z_algorithm_naive<T: Eq>(s: &[T]) -> Vec<usize> {
+    (0..s.len())
+        .map(|i| s.iter().zip(&s[i..]).take_while(|(a, b)| a == b).count())
+        .collect()
+}
@@ -0,0 +1,104 @@
+#[allow(dead_code)]
+#[path = "xorshift.rs"]
+pub mod xorshift;
+
+use xorshift::XorShift64;
+
+fn main() {
+    let z = Zobrist::new(10, 42);
+    dbg!(z.sum_hash([1, 2, 2, 3]) == z.sum_hash([2, 3, 1, 2]));
+    dbg!(z.sum_hash([1, 2, 2]) == z.sum_hash([1, 1, 2]));
+    // XOR では 2 回現れると消える
+    dbg!(z.xor_hash([1, 2, 2, 3]) == z.xor_hash([3, 1]));
+
+    // a[l..r] と b[l..r] が多重集合として等しいか
+    let (a, b) = ([3, 1, 4, 1, 5, 9], [1, 3, 1, 4, 9, 5]);
+    let (pa, pb) = (z.prefix_sum(&a), z.prefix_sum(&b));
+    let eq = |l: usize, r: usize| pa[r].wrapping_sub(pa[l]) == pb[r].wrapping_sub(pb[l]);
+    dbg!(eq(0, 2), eq(0, 4), eq(0, 5), eq(4, 6));
+
+    let mut h = MultisetHash::default();
+    h.insert(&z, 5);
+    h.insert(&z, 7);
+    h.remove(&z, 5);
+    let mut s = SetHash::default();
+    s.toggle(&z, 7);
+    dbg!(h.0 == z.key(7), s.0 == z.key(7));
+}
+
+/// 値 `0..n` にランダムな 64 bit の鍵を割り当てる．集合や多重集合のハッシュに
+#[derive(Clone, Debug)]
+pub struct Zobrist {
+    keys: Vec<u64>,
+}
+
+impl Zobrist {
+    pub fn new(n: usize, seed: u64) -> Self {
+        let mut rng = XorShift64::new(seed);
+        Self {
+            keys: (0..n).map(|_| rng.next_u64()).collect(),
+        }
+    }
+    /// 実行ごとに鍵を変える
+    pub fn from_time(n: usize) -> Self {
+        Self::new(n, XorShift64::from_time().next_u64())
+    }
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+    pub fn key(&self, x: usize) -> u64 {
+        self.keys[x]
+    }
+    /// 鍵の XOR．奇数回現れる値の集合のハッシュ
+    pub fn xor_hash(&self, a: impl IntoIterator<Item = usize>) -> u64 {
+        a.into_iter().fold(0, |h, x| h ^ self.keys[x])
+    }
+    /// 鍵の和 (mod 2^64)．多重集合のハッシュ
+    pub fn sum_hash(&self, a: impl IntoIterator<Item = usize>) -> u64 {
+        a.into_iter().fold(0, |h, x| h.wrapping_add(self.keys[x]))
+    }
+    /// `res[i]` は `a[..i]` の `xor_hash`．`a[l..r]` は `res[r] ^ res[l]`
+    pub fn prefix_xor(&self, a: &[usize]) -> Vec<u64> {
+        let mut res = vec![0u64; a.len() + 1];
+        for (i, &x) in a.iter().enumerate() {
+            res[i + 1] = res[i] ^ self.keys[x];
+        }
+        res
+    }
+    /// `res[i]` は `a[..i]` の `sum_hash`．`a[l..r]` は `res[r].wrapping_sub(res[l])`
+    pub fn prefix_sum(&self, a: &[usize]) -> Vec<u64> {
+        let mut res = vec![0u64; a.len() + 1];
+        for (i, &x) in a.iter().enumerate() {
+            res[i + 1] = res[i].wrapping_add(self.keys[x]);
+        }
+        res
+    }
+}
+
+/// 要素の追加・削除で更新できる集合のハッシュ (XOR)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct SetHash(pub u64);
+
+impl SetHash {
+    /// `x` を含んでいなければ加え，含んでいれば除く
+    pub fn toggle(&mut self, z: &Zobrist, x: usize) {
+        self.0 ^= z.key(x);
+    }
+}
+
+/// 要素の追加・削除で更新できる多重集合のハッシュ (和)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct MultisetHash(pub u64);
+
+impl MultisetHash {
+    pub fn insert(&mut self, z: &Zobrist, x: usize) {
+        self.0 = self.0.wrapping_add(z.key(x));
+    }
+    /// `x` を 1 個除く．含んでいること
+    pub fn remove(&mut self, z: &Zobrist, x: usize) {
+        self.0 = self.0.wrapping_sub(z.key(x));
+    }
+}